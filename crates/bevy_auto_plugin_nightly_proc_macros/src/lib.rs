@@ -9,49 +9,85 @@ use bevy_auto_plugin_nightly_shared::{
     update_state as nightly_update_state,
 };
 use bevy_auto_plugin_nightly_shared::{FileState, UpdateStateError};
+use bevy_auto_plugin_shared::attribute_args::{
+    is_from_file_args, is_gizmo_config_args, AddScheduleArgs, AddSystemArgs, AttributeArgs,
+    ComponentHooksArgs, ConfigureSetArgs, GizmoConfigArgs, InitResourceFromFileArgs, InitStateArgs,
+    InsertResourceArgs, OnStateArgs, OnTransitionArgs, RegisterAssetLoaderArgs,
+    RegisterDiagnosticArgs, RegisterTypeDataArgs, RequireArgs, StateScopedArgs,
+};
+use bevy_auto_plugin_shared::resource_file::resource_file_checks;
 use bevy_auto_plugin_shared::util::{
-    resolve_path_from_item_or_args, FnParamMutabilityCheckErrMessages, Target,
+    expand_asset_collection, item_derives, resolve_path_from_fn_item, resolve_path_from_impl_item,
+    resolve_path_from_item, resolve_path_from_item_or_args, resolve_reflect_trait_type_data,
+    resolve_type_data_args, strip_configure_set_attributes, sub_state_source_check,
+    FnParamMutabilityCheckErrMessages, SystemIo, Target,
 };
 use bevy_auto_plugin_shared::{
-    generate_add_events, generate_auto_names, generate_init_resources, generate_register_types,
-    util,
+    add_systems_in_set, generate_add_computed_states, generate_add_events, generate_add_observers,
+    generate_add_schedules, generate_add_sub_states, generate_add_systems,
+    generate_asset_collections, generate_asset_loaders, generate_auto_names,
+    generate_component_hooks, generate_configure_sets, generate_init_assets,
+    generate_init_gizmo_groups, generate_init_non_send_resources, generate_init_resources,
+    generate_init_resources_from_file, generate_init_states, generate_insert_gizmo_configs,
+    generate_insert_resources, generate_one_shot_systems_resource, generate_plugin_system_set,
+    generate_register_diagnostics, generate_register_one_shot_systems, generate_register_type_data,
+    generate_register_types, generate_required_components, generate_state_scoped, util,
+    AddSystemParams, ConfigureSetParams,
 };
 use proc_macro2::{Ident, Span};
 use quote::quote;
 use syn::meta::ParseNestedMeta;
+use syn::parse::{Nothing, Parse};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
-use syn::{parse_macro_input, Error, Item, ItemFn, Path, Result, Token};
+use syn::{
+    parse_macro_input, parse_quote, Error, Item, ItemConst, ItemEnum, ItemFn, ItemStruct, Path,
+    Result, Token,
+};
 
+#[cfg(feature = "nightly_proc_macro_span")]
 fn update_file_state<R>(file_path: String, update_fn: impl FnOnce(&mut FileState) -> R) -> R {
-    #[cfg(not(feature = "nightly_proc_macro_span"))]
-    panic!("proc_macro_span feature is required for this crate");
-    #[cfg(feature = "nightly_proc_macro_span")]
     nightly_update_file_state(file_path, update_fn)
 }
 
+#[cfg(not(feature = "nightly_proc_macro_span"))]
+fn update_file_state<R>(_file_path: String, _update_fn: impl FnOnce(&mut FileState) -> R) -> R {
+    unimplemented!("proc_macro_span feature is required for this crate")
+}
+
+#[cfg(feature = "nightly_proc_macro_span")]
 fn update_state(
     file_path: String,
     path: Path,
     target: Target,
 ) -> std::result::Result<(), UpdateStateError> {
-    #[cfg(not(feature = "nightly_proc_macro_span"))]
-    panic!("proc_macro_span feature is required for this crate");
-    #[cfg(feature = "nightly_proc_macro_span")]
     nightly_update_state(file_path, path, target)
 }
 
+#[cfg(not(feature = "nightly_proc_macro_span"))]
+fn update_state(
+    _file_path: String,
+    _path: Path,
+    _target: Target,
+) -> std::result::Result<(), UpdateStateError> {
+    unimplemented!("proc_macro_span feature is required for this crate")
+}
+
+#[cfg(feature = "nightly_proc_macro_span")]
 fn get_file_path() -> String {
-    #[cfg(not(feature = "nightly_proc_macro_span"))]
-    panic!("proc_macro_span feature is required for this crate");
-    #[cfg(feature = "nightly_proc_macro_span")]
     nightly_get_file_path()
 }
 
+#[cfg(not(feature = "nightly_proc_macro_span"))]
+fn get_file_path() -> String {
+    unimplemented!("proc_macro_span feature is required for this crate")
+}
+
 #[derive(Default)]
 struct AutoPluginAttributes {
     app_param_name: Option<Ident>,
+    system_set: bool,
 }
 
 impl AutoPluginAttributes {
@@ -59,6 +95,9 @@ impl AutoPluginAttributes {
         if meta.path.is_ident("app") {
             self.app_param_name = Some(meta.value()?.parse()?);
             Ok(())
+        } else if meta.path.is_ident("system_set") {
+            self.system_set = true;
+            Ok(())
         } else {
             Err(meta.error("unsupported attribute"))
         }
@@ -87,6 +126,27 @@ impl AutoPluginAttributes {
 ///     // Your custom logic comes here.
 /// }
 /// ```
+///
+/// # Example (system set)
+/// `system_set` generates a `PluginSet` next to the plugin fn containing every system added by the plugin,
+/// so other plugins can order against it without knowing the system names.
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_add_system(schedule = Update)]
+/// fn apply_gravity() {}
+///
+/// #[auto_plugin(app=app, system_set)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.add_systems(Update, apply_gravity.in_set(PluginSet));
+/// }
+///
+/// // generated code:
+/// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// pub struct PluginSet;
+/// ```
 #[proc_macro_attribute]
 pub fn auto_plugin(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
     let mut attrs = AutoPluginAttributes::default();
@@ -118,8 +178,11 @@ pub fn auto_plugin(attr: CompilerStream, input: CompilerStream) -> CompilerStrea
         return err.into_compile_error().into();
     }
 
-    let injected_code = match auto_plugin_inner(get_file_path(), &app_param_name) {
-        Ok(code) => code,
+    let AutoPluginOutput {
+        injected_code,
+        generated_items,
+    } = match auto_plugin_inner(get_file_path(), &app_param_name, attrs.system_set) {
+        Ok(output) => output,
         Err(err) => return err.to_compile_error().into(),
     };
 
@@ -150,12 +213,24 @@ pub fn auto_plugin(attr: CompilerStream, input: CompilerStream) -> CompilerStrea
             #injected_code
             #func_body
         }
+        #generated_items
     };
 
     CompilerStream::from(expanded)
 }
 
-fn auto_plugin_inner(file_path: String, app_param_name: &Ident) -> Result<MacroStream> {
+struct AutoPluginOutput {
+    /// injected at the start of the plugin fn body
+    injected_code: MacroStream,
+    /// emitted next to the plugin fn
+    generated_items: MacroStream,
+}
+
+fn auto_plugin_inner(
+    file_path: String,
+    app_param_name: &Ident,
+    system_set: bool,
+) -> Result<AutoPluginOutput> {
     update_file_state(file_path, |file_state| {
         if file_state.plugin_registered {
             return Err(Error::new(
@@ -164,6 +239,15 @@ fn auto_plugin_inner(file_path: String, app_param_name: &Ident) -> Result<MacroS
             ));
         }
         file_state.plugin_registered = true;
+        let (plugin_system_set, plugin_system_set_declaration) =
+            system_set.then(generate_plugin_system_set).unzip();
+        if let Some(plugin_system_set) = &plugin_system_set {
+            add_systems_in_set(&mut file_state.context.add_systems, plugin_system_set);
+        }
+        let register_type_data = generate_register_type_data(
+            app_param_name,
+            file_state.context.register_type_data.drain(),
+        )?;
         let register_types = generate_register_types(
             app_param_name,
             file_state.context.register_types.clone().drain(),
@@ -172,13 +256,99 @@ fn auto_plugin_inner(file_path: String, app_param_name: &Ident) -> Result<MacroS
             generate_add_events(app_param_name, file_state.context.add_events.drain())?;
         let init_resources =
             generate_init_resources(app_param_name, file_state.context.init_resources.drain())?;
+        let init_states =
+            generate_init_states(app_param_name, file_state.context.init_states.drain())?;
+        let add_sub_states =
+            generate_add_sub_states(app_param_name, file_state.context.add_sub_states.drain())?;
+        let add_computed_states = generate_add_computed_states(
+            app_param_name,
+            file_state.context.add_computed_states.drain(),
+        )?;
+        let init_non_send_resources = generate_init_non_send_resources(
+            app_param_name,
+            file_state.context.init_non_send_resources.drain(),
+        )?;
+        let init_resources_from_file = generate_init_resources_from_file(
+            app_param_name,
+            file_state.context.init_resources_from_file.drain(),
+        )?;
+        let insert_resources =
+            generate_insert_resources(app_param_name, file_state.context.insert_resources.drain())?;
+        let init_assets =
+            generate_init_assets(app_param_name, file_state.context.init_assets.drain())?;
+        let asset_loaders =
+            generate_asset_loaders(app_param_name, file_state.context.asset_loaders.drain(..))?;
+        let asset_collections = generate_asset_collections(
+            app_param_name,
+            file_state.context.asset_collections.drain(),
+            plugin_system_set.as_ref(),
+        )?;
+        let init_gizmo_groups = generate_init_gizmo_groups(
+            app_param_name,
+            file_state.context.init_gizmo_groups.drain(),
+        )?;
+        let insert_gizmo_configs = generate_insert_gizmo_configs(
+            app_param_name,
+            file_state.context.insert_gizmo_configs.drain(),
+        )?;
+        let register_diagnostics = generate_register_diagnostics(
+            app_param_name,
+            file_state.context.register_diagnostics.drain(),
+        )?;
         let auto_names =
             generate_auto_names(app_param_name, file_state.context.auto_names.drain())?;
-        Ok(quote! {
-            #register_types
-            #add_events
-            #init_resources
-            #auto_names
+        let required_components = generate_required_components(
+            app_param_name,
+            file_state.context.required_components.drain(),
+        )?;
+        let state_scoped =
+            generate_state_scoped(app_param_name, file_state.context.state_scoped.drain())?;
+        let component_hooks =
+            generate_component_hooks(app_param_name, file_state.context.component_hooks.drain(..))?;
+        let add_schedules =
+            generate_add_schedules(app_param_name, file_state.context.add_schedules.drain(..))?;
+        let configure_sets =
+            generate_configure_sets(app_param_name, file_state.context.configure_sets.drain(..))?;
+        let add_systems =
+            generate_add_systems(app_param_name, file_state.context.add_systems.drain(..))?;
+        let add_observers =
+            generate_add_observers(app_param_name, file_state.context.add_observers.drain())?;
+        let one_shot_systems = std::mem::take(&mut file_state.context.one_shot_systems);
+        let one_shot_systems_resource = generate_one_shot_systems_resource(&one_shot_systems)?;
+        let register_one_shot_systems =
+            generate_register_one_shot_systems(app_param_name, &one_shot_systems)?;
+        Ok(AutoPluginOutput {
+            injected_code: quote! {
+                #register_types
+                #register_type_data
+                #add_events
+                #init_resources
+                #init_non_send_resources
+                #init_resources_from_file
+                #insert_resources
+                #init_states
+                #add_sub_states
+                #add_computed_states
+                #init_assets
+                #asset_loaders
+                #asset_collections
+                #init_gizmo_groups
+                #insert_gizmo_configs
+                #register_diagnostics
+                #auto_names
+                #required_components
+                #state_scoped
+                #component_hooks
+                #add_schedules
+                #configure_sets
+                #add_systems
+                #register_one_shot_systems
+                #add_observers
+            },
+            generated_items: quote! {
+                #plugin_system_set_declaration
+                #one_shot_systems_resource
+            },
         })
     })
 }
@@ -217,6 +387,116 @@ fn handle_attribute(attr: CompilerStream, input: CompilerStream, target: Target)
     .unwrap_or_else(|err| err.to_compile_error().into())
 }
 
+/// For struct or enum attributes with `key = value` arguments
+fn handle_args_attribute<A: AttributeArgs>(
+    attr: CompilerStream,
+    input: CompilerStream,
+    target: impl FnOnce(A) -> Target,
+) -> CompilerStream {
+    let cloned_input = input.clone();
+    let parsed_item = parse_macro_input!(input as Item);
+
+    A::from_tokens(attr.into())
+        .and_then(|args| {
+            let path = args.resolve_path(&parsed_item)?;
+            update_state(get_file_path(), path, target(args))
+                .map_err(|err| Error::new(Span::call_site(), err))
+        })
+        .map(|_| cloned_input)
+        .unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+/// For struct or enum attributes with arguments that are not `key = value` pairs
+fn handle_parse_attribute<A: Parse>(
+    attr: CompilerStream,
+    input: CompilerStream,
+    target: impl FnOnce(A) -> Target,
+) -> CompilerStream {
+    let cloned_input = input.clone();
+    let parsed_item = parse_macro_input!(input as Item);
+    let args = parse_macro_input!(attr as A);
+
+    resolve_path_from_item(&parsed_item)
+        .and_then(|path| {
+            update_state(get_file_path(), path, target(args))
+                .map_err(|err| Error::new(Span::call_site(), err))
+        })
+        .map(|_| cloned_input)
+        .unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+fn handle_fn_attribute_inner(
+    file_path: String,
+    item: Item,
+    attr_span: Span,
+    target: Target,
+) -> Result<()> {
+    let path = resolve_path_from_fn_item(&item)?;
+
+    update_state(file_path, path, target).map_err(|err| Error::new(attr_span, err))?;
+
+    Ok(())
+}
+
+/// For fn attributes without arguments
+fn handle_fn_attribute(
+    attr: CompilerStream,
+    input: CompilerStream,
+    target: impl FnOnce(&Item) -> Result<Target>,
+) -> CompilerStream {
+    let cloned_input = input.clone();
+    let parsed_item = parse_macro_input!(input as Item);
+    if !attr.is_empty() {
+        return Error::new(
+            MacroStream::from(attr).span(),
+            "attribute does not accept arguments",
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    target(&parsed_item)
+        .and_then(|target| {
+            handle_fn_attribute_inner(get_file_path(), parsed_item, Span::call_site(), target)
+        })
+        .map(|_| cloned_input)
+        .unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+fn handle_add_system_attribute(
+    attr: CompilerStream,
+    input: CompilerStream,
+    parse_args: impl FnOnce(MacroStream) -> Result<AddSystemArgs>,
+) -> CompilerStream {
+    let cloned_input = input.clone();
+    let parsed_item = parse_macro_input!(input as Item);
+    let args = match parse_args(attr.into()) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let config_macro = match &parsed_item {
+        Item::Fn(item_fn) => {
+            AddSystemParams::config_macro(&Path::from(item_fn.sig.ident.clone()), &args)
+        }
+        _ => MacroStream::new(),
+    };
+
+    handle_fn_attribute_inner(
+        get_file_path(),
+        parsed_item,
+        Span::call_site(),
+        Target::AddSystems(Box::new(args)),
+    )
+    .map(|_| {
+        let input = MacroStream::from(cloned_input);
+        CompilerStream::from(quote! {
+            #input
+            #config_macro
+        })
+    })
+    .unwrap_or_else(|err| err.to_compile_error().into())
+}
+
 /// Automatically registers a type with the Bevy `App`.
 ///
 /// # Example (without generics)
@@ -258,6 +538,118 @@ fn handle_attribute(attr: CompilerStream, input: CompilerStream, target: Target)
 pub fn auto_register_type(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
     handle_attribute(attr, input, Target::RegisterTypes)
 }
+
+/// Automatically registers reflect type data for a type in the Bevy `App`, the type itself must be registered.
+///
+/// # Example (without generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_register_type]
+/// #[auto_register_type_data(ReflectDefault)]
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct FooComponent;
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.register_type::<FooComponent>();
+///     app.register_type_data::<FooComponent, ReflectDefault>();
+/// }
+/// ```
+///
+/// # Example (with generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_register_type(FooComponentWithGeneric<u32>)]
+/// #[auto_register_type_data(FooComponentWithGeneric<u32>, ReflectDefault)]
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct FooComponentWithGeneric<T>(T);
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.register_type::<FooComponentWithGeneric<u32>>();
+///     app.register_type_data::<FooComponentWithGeneric<u32>, ReflectDefault>();
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_register_type_data(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    let cloned_input = input.clone();
+    let parsed_item = parse_macro_input!(input as Item);
+    let args = parse_macro_input!(attr as RegisterTypeDataArgs);
+
+    resolve_type_data_args(&parsed_item, args)
+        .and_then(|(path, type_data)| {
+            update_state(get_file_path(), path, Target::RegisterTypeData(type_data))
+                .map_err(|err| Error::new(Span::call_site(), err))
+        })
+        .map(|_| cloned_input)
+        .unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+/// Automatically registers the `ReflectTrait` type data of a `#[reflect_trait]` trait for the implementing type,
+/// the implementing type must be registered.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[reflect_trait]
+/// trait Ability {
+///     fn name(&self) -> &'static str;
+/// }
+///
+/// #[auto_register_type]
+/// #[derive(Reflect)]
+/// struct Fireball;
+///
+/// #[auto_register_reflect_trait]
+/// impl Ability for Fireball {
+///     fn name(&self) -> &'static str {
+///         "fireball"
+///     }
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.register_type::<Fireball>();
+///     app.register_type_data::<Fireball, ReflectAbility>();
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_register_reflect_trait(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    let cloned_input = input.clone();
+    let parsed_item = parse_macro_input!(input as Item);
+    if !attr.is_empty() {
+        return Error::new(
+            MacroStream::from(attr).span(),
+            "attribute does not accept arguments",
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    resolve_path_from_impl_item(&parsed_item)
+        .and_then(|path| {
+            let type_data = resolve_reflect_trait_type_data(&parsed_item)?;
+            update_state(
+                get_file_path(),
+                path,
+                Target::RegisterTypeData(vec![type_data]),
+            )
+            .map_err(|err| Error::new(Span::call_site(), err))
+        })
+        .map(|_| cloned_input)
+        .unwrap_or_else(|err| err.to_compile_error().into())
+}
 /// Automatically adds an event type to the Bevy `App`.
 ///
 /// # Example (without generics)
@@ -295,6 +687,8 @@ pub fn auto_add_event(attr: CompilerStream, input: CompilerStream) -> CompilerSt
 }
 /// Automatically initializes a resource in the Bevy `App`.
 ///
+/// `!Send` types can't implement `Resource`, use `#[auto_init_non_send_resource]` for them instead.
+///
 /// # Example (without generics)
 /// ```no_run
 /// use bevy::prelude::*;
@@ -325,10 +719,119 @@ pub fn auto_add_event(attr: CompilerStream, input: CompilerStream) -> CompilerSt
 ///     app.init_resource::<FooResourceWithGeneric<bool>>();
 /// }
 /// ```
+/// # Example (from file)
+/// The `.toml` or `.ron` file is read relative to `CARGO_MANIFEST_DIR`, each format requires the `toml` or `ron` feature.
+/// Syntax errors are compile errors, and a `#[test]` deserializing the file into the resource is emitted next to it so
+/// missing fields and mismatched types fail `cargo test`. The parsers are re-exported by `bevy_auto_plugin`,
+/// only `serde` is needed to derive `Deserialize`.
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_resource(from_file = "assets/config/physics.toml")]
+/// #[derive(Resource, serde::Deserialize)]
+/// struct PhysicsConfig {
+///     gravity: f32,
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.insert_resource::<PhysicsConfig>(
+///         ::bevy_auto_plugin::__private::toml::from_str::<PhysicsConfig>(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", "assets/config/physics.toml")))
+///             .expect(concat!("assets/config/physics.toml", " does not deserialize into ", stringify!(PhysicsConfig))),
+///     );
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn auto_init_resource(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    if is_from_file_args(&attr.clone().into()) {
+        let cloned_input = input.clone();
+        let parsed_item = parse_macro_input!(input as Item);
+        let checks = match InitResourceFromFileArgs::from_tokens(attr.clone().into())
+            .and_then(|args| resource_file_checks(&parsed_item, &args))
+        {
+            Ok(checks) => checks,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        let output = handle_args_attribute(attr, cloned_input, |args: InitResourceFromFileArgs| {
+            Target::InitResourcesFromFile(Box::new(args))
+        });
+        let output = MacroStream::from(output);
+        return CompilerStream::from(quote! {
+            #output
+            #checks
+        });
+    }
     handle_attribute(attr, input, Target::InitResources)
 }
+
+/// Automatically initializes a `!Send` resource in the Bevy `App`.
+///
+/// # Example (without generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_non_send_resource]
+/// #[derive(Default)]
+/// struct FooNonSendResource(std::marker::PhantomData<*const ()>);
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     app.init_non_send_resource::<FooNonSendResource>();
+/// }
+/// ```
+/// # Example (with generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_non_send_resource(FooNonSendResourceWithGeneric<bool>)]
+/// #[derive(Default)]
+/// struct FooNonSendResourceWithGeneric<T>(std::marker::PhantomData<*const T>);
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     app.init_non_send_resource::<FooNonSendResourceWithGeneric<bool>>();
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_init_non_send_resource(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_attribute(attr, input, Target::InitNonSendResources)
+}
+
+/// Automatically inserts a resource built from the provided expression into the Bevy `App`.
+///
+/// Useful for resources without a `Default` or `FromWorld` implementation.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_insert_resource(value = FooResource::new(10))]
+/// #[derive(Resource)]
+/// struct FooResource(usize);
+///
+/// impl FooResource {
+///     fn new(value: usize) -> Self {
+///         Self(value)
+///     }
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.insert_resource::<FooResource>(FooResource::new(10));
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_insert_resource(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_args_attribute(attr, input, |args: InsertResourceArgs| {
+        Target::InsertResources(Box::new(args))
+    })
+}
 /// Automatically associates a required component `Name` with the default value set to the ident in the Bevy `App`.
 /// # Example (without generics)
 /// ```no_run
@@ -373,3 +876,962 @@ pub fn auto_init_resource(attr: CompilerStream, input: CompilerStream) -> Compil
 pub fn auto_name(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
     handle_attribute(attr, input, Target::RequiredComponentAutoName)
 }
+
+/// Automatically adds a system to the Bevy `App` in the given schedule.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_add_system(schedule = Update)]
+/// fn foo_system() {}
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.add_systems(Update, foo_system);
+/// }
+/// ```
+///
+/// # Example (with ordering)
+/// `before`, `after` and `in_set` can be repeated.
+/// Systems sharing a `chain` group in the same schedule are chained in declaration order.
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct PhysicsSet;
+///
+/// #[auto_add_system(schedule = Update, in_set = PhysicsSet)]
+/// fn movement() {}
+///
+/// #[auto_add_system(schedule = Update, after = movement, chain = collisions)]
+/// fn detect_collisions() {}
+///
+/// #[auto_add_system(schedule = Update, chain = collisions)]
+/// fn resolve_collisions() {}
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.add_systems(Update, movement.in_set(PhysicsSet));
+///     app.add_systems(Update, (detect_collisions.after(movement), resolve_collisions).chain());
+/// }
+/// ```
+///
+/// # Example (with run conditions)
+/// Multiple `run_if` conditions are combined with `and`.
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Playing,
+/// }
+///
+/// #[derive(Resource)]
+/// struct Paused;
+///
+/// #[auto_add_system(schedule = Update, run_if = in_state(GameState::Playing), run_if = not(resource_exists::<Paused>))]
+/// fn foo_system() {}
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.add_systems(Update, foo_system.run_if(in_state(GameState::Playing).and(not(resource_exists::<Paused>))));
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_add_system(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_add_system_attribute(attr, input, AddSystemArgs::from_tokens)
+}
+
+/// Automatically adds a system to the Bevy `App` in the `OnEnter` schedule of the given state.
+///
+/// Accepts the same ordering and condition arguments as `auto_add_system`.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+/// }
+///
+/// #[auto_on_enter(state = GameState::Menu)]
+/// fn spawn_menu() {}
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.add_systems(OnEnter(GameState::Menu), spawn_menu);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_on_enter(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_add_system_attribute(attr, input, |attr| {
+        Ok(OnStateArgs::from_tokens(attr)?.on_enter())
+    })
+}
+
+/// Automatically adds a system to the Bevy `App` in the `OnExit` schedule of the given state.
+///
+/// Accepts the same ordering and condition arguments as `auto_add_system`.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+/// }
+///
+/// #[auto_on_exit(state = GameState::Menu)]
+/// fn despawn_menu() {}
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.add_systems(OnExit(GameState::Menu), despawn_menu);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_on_exit(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_add_system_attribute(attr, input, |attr| {
+        Ok(OnStateArgs::from_tokens(attr)?.on_exit())
+    })
+}
+
+/// Automatically adds a system to the Bevy `App` in the `OnTransition` schedule of the given states.
+///
+/// Accepts the same ordering and condition arguments as `auto_add_system`.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Playing,
+/// }
+///
+/// #[auto_on_transition(exited = GameState::Menu, entered = GameState::Playing)]
+/// fn start_game() {}
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.add_systems(OnTransition { exited: GameState::Menu, entered: GameState::Playing }, start_game);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_on_transition(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_add_system_attribute(attr, input, |attr| {
+        Ok(OnTransitionArgs::from_tokens(attr)?.on_transition())
+    })
+}
+
+/// Automatically registers a one-shot system with the Bevy `App` and stores its `SystemId`
+/// in the generated `OneShotSystems` resource, in a field named after the fn.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_register_one_shot]
+/// fn spawn_wave(mut commands: Commands) {}
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     let one_shot_systems = OneShotSystems {
+///         spawn_wave: app.register_system(spawn_wave),
+///     };
+///     app.insert_resource(one_shot_systems);
+/// }
+///
+/// // generated code:
+/// #[derive(Resource, Debug, Copy, Clone)]
+/// pub struct OneShotSystems {
+///     pub spawn_wave: SystemId<(), ()>,
+/// }
+///
+/// fn trigger_wave(mut commands: Commands, one_shot_systems: Res<OneShotSystems>) {
+///     commands.run_system(one_shot_systems.spawn_wave);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_register_one_shot(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_fn_attribute(attr, input, |item| {
+        Ok(Target::RegisterOneShotSystems(Box::new(
+            SystemIo::from_item(item)?,
+        )))
+    })
+}
+
+/// Automatically adds a global observer to the Bevy `App`.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[derive(Event)]
+/// struct FooEvent;
+///
+/// #[auto_add_observer]
+/// fn on_foo_event(trigger: Trigger<FooEvent>) {}
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.add_observer(on_foo_event);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_add_observer(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_fn_attribute(attr, input, |_| Ok(Target::AddObservers))
+}
+
+/// Automatically initializes a state in the Bevy `App`.
+///
+/// Requires the `StatesPlugin` (included in `DefaultPlugins`) to be added before the plugin.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_state]
+/// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Playing,
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_state::<GameState>();
+/// }
+/// ```
+///
+/// # Example (with initial value)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_state(value = GameState::Playing)]
+/// #[derive(States, Debug, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     Menu,
+///     Playing,
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.insert_state::<GameState>(GameState::Playing);
+/// }
+/// ```
+///
+/// # Example (with state scoped entities)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_state(scoped_entities)]
+/// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Playing,
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_state::<GameState>();
+///     app.enable_state_scoped_entities::<GameState>();
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_init_state(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_args_attribute(attr, input, |args: InitStateArgs| {
+        Target::InitStates(Box::new(args))
+    })
+}
+
+/// Automatically adds a sub state to the Bevy `App`.
+///
+/// The type in the `#[source(...)]` attribute is checked to be a `States` type.
+///
+/// # Example (without generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_state]
+/// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Playing,
+/// }
+///
+/// #[auto_add_sub_state]
+/// #[derive(SubStates, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// #[source(GameState = GameState::Playing)]
+/// enum PauseState {
+///     #[default]
+///     Running,
+///     Paused,
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_state::<GameState>();
+///     app.add_sub_state::<PauseState>();
+/// }
+/// ```
+///
+/// # Example (with generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_state]
+/// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Playing,
+/// }
+///
+/// #[auto_add_sub_state(FooSubState<bool>)]
+/// #[derive(SubStates, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// #[source(GameState = GameState::Playing)]
+/// enum FooSubState<T: Send + Sync + Clone + Eq + std::hash::Hash + std::fmt::Debug + 'static> {
+///     #[default]
+///     Foo,
+///     Bar(T),
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_state::<GameState>();
+///     app.add_sub_state::<FooSubState<bool>>();
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_add_sub_state(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    let cloned_input = input.clone();
+    let parsed_item = parse_macro_input!(input as Item);
+    let source_check = match sub_state_source_check(&parsed_item) {
+        Ok(source_check) => source_check,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let output = handle_attribute(attr, cloned_input, Target::AddSubStates);
+    let output = MacroStream::from(output);
+    CompilerStream::from(quote! {
+        #output
+        #source_check
+    })
+}
+
+/// Automatically adds a computed state to the Bevy `App`.
+///
+/// # Example (without generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_state]
+/// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Playing,
+/// }
+///
+/// #[auto_add_computed_state]
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// struct InGame;
+///
+/// impl ComputedStates for InGame {
+///     type SourceStates = GameState;
+///
+///     fn compute(sources: GameState) -> Option<Self> {
+///         (sources == GameState::Playing).then_some(InGame)
+///     }
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_state::<GameState>();
+///     app.add_computed_state::<InGame>();
+/// }
+/// ```
+///
+/// # Example (with generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_state]
+/// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Playing,
+/// }
+///
+/// #[auto_add_computed_state(InGame<bool>)]
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// struct InGame<T>(std::marker::PhantomData<T>);
+///
+/// impl<T: Send + Sync + Clone + Eq + std::hash::Hash + std::fmt::Debug + 'static> ComputedStates for InGame<T> {
+///     type SourceStates = GameState;
+///
+///     fn compute(sources: GameState) -> Option<Self> {
+///         (sources == GameState::Playing).then_some(InGame(std::marker::PhantomData))
+///     }
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_state::<GameState>();
+///     app.add_computed_state::<InGame<bool>>();
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_add_computed_state(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_attribute(attr, input, Target::AddComputedStates)
+}
+
+/// Automatically makes `StateScoped` a required component, despawning the entity when the state is exited.
+///
+/// Requires `#[auto_init_state(scoped_entities)]` (or `enable_state_scoped_entities`) on the state.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_state(scoped_entities)]
+/// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     InGame,
+/// }
+///
+/// #[auto_state_scoped(GameState::InGame)]
+/// #[derive(Component)]
+/// struct Level;
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_state::<GameState>();
+///     app.enable_state_scoped_entities::<GameState>();
+///     app.register_required_components_with::<Level, StateScoped<_>>(|| StateScoped(GameState::InGame));
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_state_scoped(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_parse_attribute(attr, input, |args: StateScopedArgs| {
+        Target::StateScoped(Box::new(args))
+    })
+}
+
+/// Automatically registers required components for a component, with an optional constructor expression.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[derive(Component)]
+/// struct Health(usize);
+///
+/// #[auto_require(Transform, Visibility = Visibility::Hidden, Health = Health(100))]
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.register_required_components::<Player, Transform>();
+///     app.register_required_components_with::<Player, Visibility>(|| Visibility::Hidden);
+///     app.register_required_components_with::<Player, Health>(|| Health(100));
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_require(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_parse_attribute(attr, input, |args: RequireArgs| {
+        Target::RequiredComponents(Box::new(args))
+    })
+}
+
+/// Automatically registers component lifecycle hooks for a component.
+///
+/// Registering the same hook twice for a component is a compile error.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::ecs::component::ComponentId;
+/// use bevy::ecs::world::DeferredWorld;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_component_hooks(on_add = foo_on_add, on_remove = foo_on_remove)]
+/// #[derive(Component)]
+/// struct Foo;
+///
+/// fn foo_on_add(world: DeferredWorld, entity: Entity, component_id: ComponentId) {}
+///
+/// fn foo_on_remove(world: DeferredWorld, entity: Entity, component_id: ComponentId) {}
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.world_mut().register_component_hooks::<Foo>().on_add(foo_on_add);
+///     app.world_mut().register_component_hooks::<Foo>().on_remove(foo_on_remove);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_component_hooks(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_args_attribute(attr, input, |args: ComponentHooksArgs| {
+        Target::ComponentHooks(Box::new(args))
+    })
+}
+
+/// Automatically initializes an asset type in the Bevy `App`.
+///
+/// When the asset derives `Reflect`, the asset and its `Handle` are registered for reflection as well.
+///
+/// # Example (without generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_asset]
+/// #[derive(Asset, Reflect)]
+/// struct FooAsset {
+///     value: usize,
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_asset::<FooAsset>();
+///     app.register_asset_reflect::<FooAsset>();
+///     app.register_type::<Handle<FooAsset>>();
+/// }
+/// ```
+///
+/// # Example (with generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_asset(FooAssetWithGeneric<u32>)]
+/// #[derive(Asset, TypePath)]
+/// struct FooAssetWithGeneric<T: Send + Sync + TypePath>(T);
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_asset::<FooAssetWithGeneric<u32>>();
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_init_asset(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    let cloned_input = input.clone();
+    let parsed_item = parse_macro_input!(input as Item);
+    let reflect = match item_derives(&parsed_item, "Reflect") {
+        Ok(reflect) => reflect,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    handle_attribute(attr, cloned_input, Target::InitAssets { reflect })
+}
+
+/// Automatically initializes an asset loader in the Bevy `App`, after the asset types are initialized.
+///
+/// The loader must implement `AssetLoader` and `FromWorld` (or `Default`).
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_asset]
+/// #[derive(Asset, TypePath)]
+/// struct FooAsset(Vec<u8>);
+///
+/// #[auto_init_asset_loader]
+/// #[derive(Default)]
+/// struct FooAssetLoader;
+///
+/// impl AssetLoader for FooAssetLoader {
+///     type Asset = FooAsset;
+///     type Settings = ();
+///     type Error = std::io::Error;
+///
+///     async fn load(
+///         &self,
+///         reader: &mut dyn Reader,
+///         _settings: &(),
+///         _load_context: &mut LoadContext<'_>,
+///     ) -> Result<FooAsset, Self::Error> {
+///         let mut bytes = Vec::new();
+///         reader.read_to_end(&mut bytes).await?;
+///         Ok(FooAsset(bytes))
+///     }
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_asset::<FooAsset>();
+///     app.init_asset_loader::<FooAssetLoader>();
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_init_asset_loader(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_attribute(attr, input, Target::InitAssetLoaders)
+}
+
+/// Automatically registers an asset loader built from the provided expression in the Bevy `App`,
+/// after the asset types are initialized.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_asset]
+/// #[derive(Asset, TypePath)]
+/// struct FooAsset(Vec<u8>);
+///
+/// #[auto_register_asset_loader(value = FooAssetLoader { max_size: 1024 })]
+/// struct FooAssetLoader {
+///     max_size: usize,
+/// }
+///
+/// impl AssetLoader for FooAssetLoader {
+///     type Asset = FooAsset;
+///     type Settings = ();
+///     type Error = std::io::Error;
+///
+///     async fn load(
+///         &self,
+///         reader: &mut dyn Reader,
+///         _settings: &(),
+///         _load_context: &mut LoadContext<'_>,
+///     ) -> Result<FooAsset, Self::Error> {
+///         let mut bytes = Vec::new();
+///         reader.read_to_end(&mut bytes).await?;
+///         bytes.truncate(self.max_size);
+///         Ok(FooAsset(bytes))
+///     }
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_asset::<FooAsset>();
+///     app.register_asset_loader::<FooAssetLoader>(FooAssetLoader { max_size: 1024 });
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_register_asset_loader(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_args_attribute(attr, input, |args: RegisterAssetLoaderArgs| {
+        Target::RegisterAssetLoaders(Box::new(args))
+    })
+}
+
+/// Automatically inserts a resource of asset handles at `Startup`, loading each `#[asset(path = "...")]` field
+/// with the `AssetServer`. Fields without `#[asset]` are set to `Default::default()`.
+///
+/// Use the `asset_collection_loaded::<T>()` run condition to wait until every asset of the collection is loaded.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::asset_collection::asset_collection_loaded;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_asset_collection]
+/// #[derive(Resource)]
+/// struct PlayerAssets {
+///     #[asset(path = "textures/player.png")]
+///     sprite: Handle<Image>,
+///     #[asset(path = "audio/jump.ogg")]
+///     jump: Handle<AudioSource>,
+/// }
+///
+/// #[auto_add_system(schedule = Update, run_if = asset_collection_loaded::<PlayerAssets>())]
+/// fn spawn_player(mut commands: Commands, assets: Res<PlayerAssets>) {
+///     commands.spawn(Sprite::from_image(assets.sprite.clone()));
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     // `load_system` inserts `PlayerAssets` with `asset_server.load("...")` for each `#[asset]` field
+///     app.add_systems(Startup, <PlayerAssets as AssetCollection>::load_system());
+///     app.add_systems(Update, spawn_player.run_if(asset_collection_loaded::<PlayerAssets>()));
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_asset_collection(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    parse_macro_input!(attr as Nothing);
+    let mut item = parse_macro_input!(input as ItemStruct);
+    expand_asset_collection(&mut item)
+        .and_then(|asset_collection| {
+            let ident = &item.ident;
+            update_state(
+                get_file_path(),
+                parse_quote!(#ident),
+                Target::AssetCollections,
+            )
+            .map_err(|err| Error::new(Span::call_site(), err))?;
+            Ok(CompilerStream::from(quote! {
+                #item
+                #asset_collection
+            }))
+        })
+        .unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+/// Automatically configures the variants of a `SystemSet` enum in the given schedule.
+///
+/// `chain` orders the variants in declaration order. `before`, `after` and `run_if` can be repeated and apply to
+/// every variant, multiple `run_if` conditions are combined with `and`. Individual variants accept the same
+/// ordering and condition arguments with `#[configure_set(...)]`.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct InputSet;
+///
+/// #[auto_configure_set(schedule = Update, chain, run_if = in_state(GameState::Playing))]
+/// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// enum GameplaySet {
+///     #[configure_set(after = InputSet)]
+///     Movement,
+///     Physics,
+///     #[configure_set(run_if = resource_exists::<Score>)]
+///     Scoring,
+/// }
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.configure_sets(
+///         Update,
+///         (
+///             GameplaySet::Movement.after(InputSet),
+///             GameplaySet::Physics,
+///             GameplaySet::Scoring.run_if(resource_exists::<Score>),
+///         )
+///             .chain()
+///             .run_if(in_state(GameState::Playing)),
+///     );
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_configure_set(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    let mut item = parse_macro_input!(input as ItemEnum);
+    ConfigureSetArgs::from_tokens(attr.into())
+        .and_then(|args| {
+            let ident = &item.ident;
+            let path: Path = parse_quote!(#ident);
+            let params = ConfigureSetParams::new(&path, &item, &args)?;
+            update_state(
+                get_file_path(),
+                path,
+                Target::ConfigureSets(Box::new(params)),
+            )
+            .map_err(|err| Error::new(Span::call_site(), err))
+        })
+        .map(|_| {
+            strip_configure_set_attributes(&mut item);
+            CompilerStream::from(quote! { #item })
+        })
+        .unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+/// Automatically initializes a custom schedule in the Bevy `App` and inserts it into `MainScheduleOrder`.
+///
+/// Exactly one of `before` or `after` positions the schedule. `startup` positions it among the startup schedules
+/// and `fixed` in `FixedMainScheduleOrder` instead. `executor` sets the `ExecutorKind` and `ambiguity_detection`
+/// the `LogLevel` of the schedule ambiguity detection.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::ecs::schedule::ScheduleLabel;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_add_schedule(after = Update, executor = SingleThreaded, ambiguity_detection = Warn)]
+/// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct Simulation;
+///
+/// #[auto_add_schedule(before = FixedUpdate, fixed)]
+/// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct FixedSimulation;
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_schedule(Simulation);
+///     app.world_mut()
+///         .resource_mut::<MainScheduleOrder>()
+///         .insert_after(Update, Simulation);
+///     app.edit_schedule(Simulation, |schedule| {
+///         schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+///         schedule.set_build_settings(ScheduleBuildSettings {
+///             ambiguity_detection: LogLevel::Warn,
+///             ..schedule.get_build_settings()
+///         });
+///     });
+///     app.init_schedule(FixedSimulation);
+///     app.world_mut()
+///         .resource_mut::<FixedMainScheduleOrder>()
+///         .insert_before(FixedUpdate, FixedSimulation);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_add_schedule(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_args_attribute(attr, input, |args: AddScheduleArgs| {
+        Target::AddSchedules(Box::new(args))
+    })
+}
+
+/// Automatically initializes a custom `GizmoConfigGroup` in the Bevy `App`.
+///
+/// # Example (without generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_gizmo_group]
+/// #[derive(GizmoConfigGroup, Default, Reflect)]
+/// struct DebugGizmos;
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_gizmo_group::<DebugGizmos>();
+/// }
+/// ```
+/// # Example (with generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_gizmo_group(DebugGizmosWithGeneric<bool>)]
+/// #[derive(GizmoConfigGroup, Default, Reflect)]
+/// struct DebugGizmosWithGeneric<T: TypePath + Default + Send + Sync>(T);
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.init_gizmo_group::<DebugGizmosWithGeneric<bool>>();
+/// }
+/// ```
+/// # Example (with config)
+/// `config` inserts the group with a non-default `GizmoConfig`, generic groups pass their generics with
+/// `generics(...)`, e.g. `#[auto_init_gizmo_group(config = GizmoConfig::default(), generics(bool))]`.
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_init_gizmo_group(config = GizmoConfig { line_width: 4.0, ..default() })]
+/// #[derive(GizmoConfigGroup, Default, Reflect)]
+/// struct DebugGizmos;
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.insert_gizmo_config(DebugGizmos::default(), GizmoConfig { line_width: 4.0, ..default() });
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_init_gizmo_group(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    if is_gizmo_config_args(&attr.clone().into()) {
+        handle_args_attribute(attr, input, |args: GizmoConfigArgs| {
+            Target::InsertGizmoConfigs(Box::new(args))
+        })
+    } else {
+        handle_attribute(attr, input, Target::InitGizmoGroups)
+    }
+}
+
+/// Automatically registers a `Diagnostic` for a `DiagnosticPath` constant in the Bevy `App`.
+///
+/// `max_history` sets the history length and `suffix` the unit suffix of the diagnostic.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::diagnostic::{Diagnostic, DiagnosticPath, RegisterDiagnostic};
+/// use bevy_auto_plugin::auto_plugin::*;
+///
+/// #[auto_register_diagnostic(max_history = 20, suffix = "ms")]
+/// pub const PATHFINDING_TIME: DiagnosticPath = DiagnosticPath::const_new("ai/pathfinding_time");
+///
+/// #[auto_plugin(app=app)]
+/// fn plugin(app: &mut App) {
+///     // generated code:
+///     app.register_diagnostic(
+///         Diagnostic::new(PATHFINDING_TIME)
+///             .with_max_history_length(20)
+///             .with_suffix("ms"),
+///     );
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_register_diagnostic(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    let cloned_input = input.clone();
+    let item = parse_macro_input!(input as ItemConst);
+    RegisterDiagnosticArgs::from_tokens(attr.into())
+        .and_then(|args| {
+            let ident = &item.ident;
+            update_state(
+                get_file_path(),
+                parse_quote!(#ident),
+                Target::RegisterDiagnostics(Box::new(args)),
+            )
+            .map_err(|err| Error::new(Span::call_site(), err))
+        })
+        .map(|_| cloned_input)
+        .unwrap_or_else(|err| err.to_compile_error().into())
+}
@@ -1,10 +1,16 @@
 #![cfg_attr(feature = "nightly_proc_macro_span", feature(proc_macro_span))]
+#![allow(unused_features)]
 use bevy_auto_plugin_shared::util::{path_to_string, Target};
-use bevy_auto_plugin_shared::AutoPluginContext;
-use proc_macro2::Span;
+use bevy_auto_plugin_shared::{
+    insert_component_hooks, AddScheduleParams, AddSystemParams, AssetLoaderParams,
+    AutoPluginContext, ComponentHookParams, DiagnosticParams, GizmoConfigParams, InitAssetParams,
+    InitResourceFromFileParams, InitStateParams, InsertResourceParams, OneShotSystemParams,
+    RequiredComponentParams, StateScopedParams, TypeDataParams,
+};
 use quote::quote;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 use syn::Path;
 use thiserror::Error;
 
@@ -20,20 +26,14 @@ pub struct FileState {
     pub context: AutoPluginContext,
 }
 
+#[cfg(feature = "nightly_proc_macro_span")]
 pub fn get_file_path() -> String {
-    #[cfg(feature = "nightly_proc_macro_span")]
-    let file_path = Span::call_site()
-        .unwrap()
-        .source_file()
-        .path()
-        .display()
-        .to_string();
+    proc_macro2::Span::call_site().unwrap().file()
+}
 
-    #[cfg(not(feature = "nightly_proc_macro_span"))]
-    let file_path = {
-        panic!("proc_macro_span feature is required for this crate");
-    };
-    file_path
+#[cfg(not(feature = "nightly_proc_macro_span"))]
+pub fn get_file_path() -> String {
+    unimplemented!("proc_macro_span feature is required for this crate")
 }
 
 pub fn update_file_state<R>(file_path: String, update_fn: impl FnOnce(&mut FileState) -> R) -> R {
@@ -55,12 +55,85 @@ pub fn update_state(
         if entry.plugin_registered {
             return Err(UpdateStateError::PluginAlreadyRegistered);
         }
-        let path = path_to_string(&path, false);
+        let path_string = path_to_string(&path, false);
         let inserted = match target {
-            Target::RegisterTypes => entry.context.register_types.insert(path),
-            Target::AddEvents => entry.context.add_events.insert(path),
-            Target::InitResources => entry.context.init_resources.insert(path),
-            Target::RequiredComponentAutoName => entry.context.auto_names.insert(path),
+            Target::RegisterTypes => entry.context.register_types.insert(path_string),
+            Target::AddEvents => entry.context.add_events.insert(path_string),
+            Target::InitResources => entry.context.init_resources.insert(path_string),
+            Target::InitNonSendResources => {
+                entry.context.init_non_send_resources.insert(path_string)
+            }
+            Target::RequiredComponentAutoName => entry.context.auto_names.insert(path_string),
+            Target::AddObservers => entry.context.add_observers.insert(path_string),
+            Target::AddSubStates => entry.context.add_sub_states.insert(path_string),
+            Target::AddComputedStates => entry.context.add_computed_states.insert(path_string),
+            Target::InitStates(args) => entry
+                .context
+                .init_states
+                .insert(InitStateParams::new(&path, &args)),
+            Target::InsertResources(args) => entry
+                .context
+                .insert_resources
+                .insert(InsertResourceParams::new(&path, &args)),
+            Target::InitResourcesFromFile(args) => entry
+                .context
+                .init_resources_from_file
+                .insert(InitResourceFromFileParams::new(&path, &args)),
+            Target::RequiredComponents(args) => insert_all_unique(
+                &mut entry.context.required_components,
+                RequiredComponentParams::from_args(&path, &args),
+            ),
+            Target::ComponentHooks(args) => insert_component_hooks(
+                &mut entry.context.component_hooks,
+                ComponentHookParams::from_args(&path, &args),
+            ),
+            Target::RegisterTypeData(type_data) => insert_all_unique(
+                &mut entry.context.register_type_data,
+                type_data
+                    .iter()
+                    .map(|type_data| TypeDataParams::new(&path, type_data)),
+            ),
+            Target::InitAssets { reflect } => entry
+                .context
+                .init_assets
+                .insert(InitAssetParams::new(&path, reflect)),
+            Target::RegisterDiagnostics(args) => entry
+                .context
+                .register_diagnostics
+                .insert(DiagnosticParams::new(&path, &args)),
+            Target::InitGizmoGroups => entry.context.init_gizmo_groups.insert(path_string),
+            Target::InsertGizmoConfigs(args) => entry
+                .context
+                .insert_gizmo_configs
+                .insert(GizmoConfigParams::new(&path, &args)),
+            Target::AssetCollections => entry.context.asset_collections.insert(path_string),
+            Target::InitAssetLoaders => insert_unique(
+                &mut entry.context.asset_loaders,
+                AssetLoaderParams::init(&path),
+            ),
+            Target::RegisterAssetLoaders(args) => insert_unique(
+                &mut entry.context.asset_loaders,
+                AssetLoaderParams::register(&path, &args),
+            ),
+            Target::StateScoped(args) => entry
+                .context
+                .state_scoped
+                .insert(StateScopedParams::new(&path, &args)),
+            Target::AddSchedules(args) => insert_unique(
+                &mut entry.context.add_schedules,
+                AddScheduleParams::new(&path, &args),
+            ),
+            Target::ConfigureSets(params) => {
+                insert_unique(&mut entry.context.configure_sets, *params)
+            }
+            Target::AddSystems(args) => insert_unique(
+                &mut entry.context.add_systems,
+                AddSystemParams::new(&path, &args),
+            ),
+            Target::RegisterOneShotSystems(system_io) => insert_unique(
+                &mut entry.context.one_shot_systems,
+                OneShotSystemParams::new(&path, &system_io),
+            ),
         };
         if !inserted {
             return Err(UpdateStateError::Duplicate);
@@ -69,6 +142,31 @@ pub fn update_state(
     })
 }
 
+/// Same semantics as [`std::collections::HashSet::insert`] but preserves insertion order
+fn insert_unique<T: PartialEq>(items: &mut Vec<T>, item: T) -> bool {
+    if items.contains(&item) {
+        return false;
+    }
+    items.push(item);
+    true
+}
+
+/// Inserts every item or none of them, `false` if any item is already present or repeated
+fn insert_all_unique<T: Eq + Hash>(
+    set: &mut HashSet<T>,
+    items: impl IntoIterator<Item = T>,
+) -> bool {
+    let mut new_items = HashSet::new();
+    if !items
+        .into_iter()
+        .all(|item| !set.contains(&item) && new_items.insert(item))
+    {
+        return false;
+    }
+    set.extend(new_items);
+    true
+}
+
 fn get_files_missing_plugin() -> Vec<String> {
     FILE_STATE_MAP.with(|map| {
         let map = map.borrow();
@@ -110,7 +208,8 @@ pub fn files_missing_plugin_ts() -> proc_macro2::TokenStream {
             }));
         }
         #[cfg(feature = "missing_auto_plugin_is_compile_error")]
-        return syn::Error::new(Span::call_site(), messages.join("\n")).to_compile_error();
+        return syn::Error::new(proc_macro2::Span::call_site(), messages.join("\n"))
+            .to_compile_error();
     }
     output
 }
@@ -1,20 +1,50 @@
 use proc_macro::TokenStream as CompilerStream;
 use proc_macro2::TokenStream as MacroStream;
 
+use bevy_auto_plugin_shared::attribute_args::{
+    is_from_file_args, is_gizmo_config_args, AddScheduleArgs, AddSystemArgs, AttributeArgs,
+    ComponentHooksArgs, ConfigureSetArgs, GizmoConfigArgs, InitResourceFromFileArgs, InitStateArgs,
+    InsertResourceArgs, OnStateArgs, OnTransitionArgs, RegisterAssetLoaderArgs,
+    RegisterDiagnosticArgs, RegisterTypeDataArgs, RequireArgs, StateScopedArgs,
+};
+use bevy_auto_plugin_shared::resource_file::resource_file_checks;
 use bevy_auto_plugin_shared::util::{
-    inject_module, items_with_attribute_macro, ItemWithAttributeMatch,
+    const_items_with_attribute_macro, expand_asset_collection, fn_items_with_attribute_macro,
+    impl_trait_items_with_attribute_macro, inject_module, item_derives, items_with_attribute_macro,
+    items_with_attribute_macro_matching, resolve_reflect_trait_type_data, resolve_type_data_args,
+    strip_configure_set_attributes, struct_or_enum_items_with_attribute_args_macro,
+    struct_or_enum_items_with_attribute_args_macro_matching,
+    struct_or_enum_items_with_attribute_args_matching, sub_state_source_check,
+    ItemWithAttributeMatch, StructOrEnumRef, SystemIo,
 };
 use bevy_auto_plugin_shared::{
-    generate_add_events, generate_auto_names, generate_init_resources, generate_register_types,
+    add_systems_in_set, generate_add_computed_states, generate_add_events, generate_add_observers,
+    generate_add_schedules, generate_add_sub_states, generate_add_systems,
+    generate_asset_collections, generate_asset_loaders, generate_auto_names,
+    generate_component_hooks, generate_configure_sets, generate_init_assets,
+    generate_init_gizmo_groups, generate_init_non_send_resources, generate_init_resources,
+    generate_init_resources_from_file, generate_init_states, generate_insert_gizmo_configs,
+    generate_insert_resources, generate_one_shot_systems_resource, generate_plugin_system_set,
+    generate_register_diagnostics, generate_register_one_shot_systems, generate_register_type_data,
+    generate_register_types, generate_required_components, generate_state_scoped,
+    insert_component_hooks, AddScheduleParams, AddSystemParams, AssetLoaderParams,
+    ComponentHookParams, ConfigureSetParams, DiagnosticParams, GizmoConfigParams, InitAssetParams,
+    InitResourceFromFileParams, InitStateParams, InsertResourceParams, OneShotSystemParams,
+    RequiredComponentParams, StateScopedParams, TypeDataParams,
 };
 use proc_macro2::{Ident, Span};
 use quote::quote;
 use syn::meta::ParseNestedMeta;
-use syn::{parse2, parse_macro_input, Item, ItemMod, Result};
+use syn::parse::Nothing;
+use syn::spanned::Spanned;
+use syn::{
+    parse2, parse_macro_input, Attribute, Error, Item, ItemConst, ItemMod, ItemStruct, Meta, Result,
+};
 
 #[derive(Default)]
 struct AutoPluginAttributes {
     init_name: Option<Ident>,
+    system_set: bool,
 }
 
 impl AutoPluginAttributes {
@@ -22,6 +52,9 @@ impl AutoPluginAttributes {
         if meta.path.is_ident("init_name") {
             self.init_name = Some(meta.value()?.parse()?);
             Ok(())
+        } else if meta.path.is_ident("system_set") {
+            self.system_set = true;
+            Ok(())
         } else {
             Err(meta.error("unsupported attribute"))
         }
@@ -59,6 +92,37 @@ impl AutoPluginAttributes {
 ///     app.add_plugin(my_plugin::init)
 /// }
 /// ```
+///
+/// # Example (system set)
+/// `system_set` generates a `PluginSet` in the module containing every system added by the plugin,
+/// so other plugins can order against it without knowing the system names.
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init, system_set)]
+/// pub mod physics {
+///     use super::*;
+///
+///     #[auto_add_system(schedule = Update)]
+///     fn apply_gravity() {}
+///
+///     // code gen:
+///     #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+///     pub struct PluginSet;
+///
+///     pub(super) fn init(app: &mut App) {
+///         app.add_systems(Update, apply_gravity.in_set(PluginSet));
+///     }
+/// }
+///
+/// fn render_debug() {}
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(physics::init);
+///     app.add_systems(Update, render_debug.after(physics::PluginSet));
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn auto_plugin(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
     let mut attrs = AutoPluginAttributes::default();
@@ -68,7 +132,7 @@ pub fn auto_plugin(attr: CompilerStream, input: CompilerStream) -> CompilerStrea
     // Parse the input module
     let module = parse_macro_input!(input as ItemMod);
 
-    let injected_module = match auto_plugin_inner(module, &attrs.init_name()) {
+    let injected_module = match auto_plugin_inner(module, &attrs.init_name(), attrs.system_set) {
         Ok(code) => code,
         Err(err) => return err.to_compile_error().into(),
     };
@@ -76,7 +140,11 @@ pub fn auto_plugin(attr: CompilerStream, input: CompilerStream) -> CompilerStrea
     CompilerStream::from(injected_module)
 }
 
-fn auto_plugin_inner(mut module: ItemMod, init_name: &Ident) -> Result<MacroStream> {
+fn auto_plugin_inner(
+    mut module: ItemMod,
+    init_name: &Ident,
+    system_set: bool,
+) -> Result<MacroStream> {
     let app_param_ident = Ident::new("app", Span::call_site());
     // Extract the content inside the module
     if let Some((_, items)) = &module.content {
@@ -91,28 +159,341 @@ fn auto_plugin_inner(mut module: ItemMod, init_name: &Ident) -> Result<MacroStre
         let auto_register_types = items_with_attribute_macro(items, "auto_register_type")?;
         let auto_register_types = map_to_string(auto_register_types);
 
+        // the registered type is resolved from the arguments, generic items can't be resolved from the item alone
+        let mut auto_register_type_data = vec![];
+        for item in items {
+            let Ok(struct_or_enum) = StructOrEnumRef::try_from(item) else {
+                continue;
+            };
+            for attr in struct_or_enum
+                .attributes
+                .iter()
+                .filter(|attr| attr.path().is_ident("auto_register_type_data"))
+            {
+                let args = attr.parse_args::<RegisterTypeDataArgs>()?;
+                let (path, type_data) = resolve_type_data_args(item, args)?;
+                auto_register_type_data.extend(
+                    type_data
+                        .iter()
+                        .map(|type_data| TypeDataParams::new(&path, type_data)),
+                );
+            }
+        }
+        for matched in impl_trait_items_with_attribute_macro(items, "auto_register_reflect_trait")?
+        {
+            matched.attributes.meta.require_path_only()?;
+            let type_data = resolve_reflect_trait_type_data(&matched.item)?;
+            auto_register_type_data.push(TypeDataParams::new(&matched.path, &type_data));
+        }
+
         let auto_add_events = items_with_attribute_macro(items, "auto_add_event")?;
         let auto_add_events = map_to_string(auto_add_events);
 
-        let auto_init_resources = items_with_attribute_macro(items, "auto_init_resource")?;
+        let is_init_resource_from_file = |attr: &Attribute| -> bool {
+            attr.path().is_ident("auto_init_resource")
+                && matches!(&attr.meta, Meta::List(list) if is_from_file_args(&list.tokens))
+        };
+
+        let auto_init_resources = items_with_attribute_macro_matching(items, |attr| {
+            attr.path().is_ident("auto_init_resource") && !is_init_resource_from_file(attr)
+        })?;
         let auto_init_resources = map_to_string(auto_init_resources);
 
+        let auto_init_non_send_resources =
+            items_with_attribute_macro(items, "auto_init_non_send_resource")?;
+        let auto_init_non_send_resources = map_to_string(auto_init_non_send_resources);
+
+        let auto_init_resources_from_file =
+            struct_or_enum_items_with_attribute_args_macro_matching(
+                items,
+                is_init_resource_from_file,
+            )?
+            .into_iter()
+            .map(|matched| {
+                let args = InitResourceFromFileArgs::from_attribute(&matched.attributes)?;
+                Ok(InitResourceFromFileParams::new(&matched.path, &args))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let auto_insert_resources =
+            struct_or_enum_items_with_attribute_args_macro(items, "auto_insert_resource")?
+                .into_iter()
+                .map(|matched| {
+                    let args = InsertResourceArgs::from_attribute(&matched.attributes)?;
+                    Ok(InsertResourceParams::new(&matched.path, &args))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+        let auto_init_assets = items_with_attribute_macro(items, "auto_init_asset")?
+            .into_iter()
+            .map(|matched| {
+                let reflect = item_derives(&matched.item, "Reflect")?;
+                Ok(InitAssetParams::new(&matched.path, reflect))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // init and register loaders share one list so they keep declaration order
+        let auto_asset_loaders =
+            struct_or_enum_items_with_attribute_args_macro_matching(items, |attr| {
+                attr.path().is_ident("auto_init_asset_loader")
+                    || attr.path().is_ident("auto_register_asset_loader")
+            })?
+            .into_iter()
+            .map(|matched| {
+                if matched.attributes.path().is_ident("auto_init_asset_loader") {
+                    return Ok(AssetLoaderParams::init(&matched.path));
+                }
+                let args = RegisterAssetLoaderArgs::from_attribute(&matched.attributes)?;
+                Ok(AssetLoaderParams::register(&matched.path, &args))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let auto_asset_collections = items_with_attribute_macro(items, "auto_asset_collection")?;
+        let auto_asset_collections = map_to_string(auto_asset_collections);
+
         let auto_names = items_with_attribute_macro(items, "auto_name")?;
         let auto_names = map_to_string(auto_names);
 
+        let auto_init_states =
+            struct_or_enum_items_with_attribute_args_macro(items, "auto_init_state")?
+                .into_iter()
+                .map(|matched| {
+                    let args = InitStateArgs::from_attribute(&matched.attributes)?;
+                    Ok(InitStateParams::new(&matched.path, &args))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+        let auto_required_components =
+            struct_or_enum_items_with_attribute_args_macro(items, "auto_require")?
+                .into_iter()
+                .map(|matched| {
+                    let args = matched.attributes.parse_args::<RequireArgs>()?;
+                    Ok(RequiredComponentParams::from_args(&matched.path, &args))
+                })
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .flatten();
+
+        let mut auto_component_hooks = vec![];
+        for matched in
+            struct_or_enum_items_with_attribute_args_macro(items, "auto_component_hooks")?
+        {
+            let args = ComponentHooksArgs::from_attribute(&matched.attributes)?;
+            let hooks = ComponentHookParams::from_args(&matched.path, &args);
+            if !insert_component_hooks(&mut auto_component_hooks, hooks) {
+                return Err(Error::new(
+                    matched.attributes.span(),
+                    "duplicate component hook",
+                ));
+            }
+        }
+
+        let auto_state_scoped =
+            struct_or_enum_items_with_attribute_args_macro(items, "auto_state_scoped")?
+                .into_iter()
+                .map(|matched| {
+                    let args = matched.attributes.parse_args::<StateScopedArgs>()?;
+                    Ok(StateScopedParams::new(&matched.path, &args))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+        let auto_add_sub_states = items_with_attribute_macro(items, "auto_add_sub_state")?;
+        let auto_add_sub_states = map_to_string(auto_add_sub_states);
+
+        let auto_add_computed_states =
+            items_with_attribute_macro(items, "auto_add_computed_state")?;
+        let auto_add_computed_states = map_to_string(auto_add_computed_states);
+
+        let is_insert_gizmo_config = |attr: &Attribute| -> bool {
+            attr.path().is_ident("auto_init_gizmo_group")
+                && matches!(&attr.meta, Meta::List(list) if is_gizmo_config_args(&list.tokens))
+        };
+
+        let auto_init_gizmo_groups = items_with_attribute_macro_matching(items, |attr| {
+            attr.path().is_ident("auto_init_gizmo_group") && !is_insert_gizmo_config(attr)
+        })?;
+        let auto_init_gizmo_groups = map_to_string(auto_init_gizmo_groups);
+
+        let auto_insert_gizmo_configs = struct_or_enum_items_with_attribute_args_matching::<
+            GizmoConfigArgs,
+        >(items, is_insert_gizmo_config)?
+        .into_iter()
+        .map(|(path, args)| GizmoConfigParams::new(&path, &args))
+        .collect::<Vec<_>>();
+
+        let auto_register_diagnostics =
+            const_items_with_attribute_macro(items, "auto_register_diagnostic")?
+                .into_iter()
+                .map(|matched| {
+                    let args = RegisterDiagnosticArgs::from_attribute(&matched.attributes)?;
+                    Ok(DiagnosticParams::new(&matched.path, &args))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+        let auto_add_schedules =
+            struct_or_enum_items_with_attribute_args_macro(items, "auto_add_schedule")?
+                .into_iter()
+                .map(|matched| {
+                    let args = AddScheduleArgs::from_attribute(&matched.attributes)?;
+                    Ok(AddScheduleParams::new(&matched.path, &args))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+        let auto_configure_sets =
+            struct_or_enum_items_with_attribute_args_macro(items, "auto_configure_set")?
+                .into_iter()
+                .map(|matched| {
+                    let Item::Enum(item) = &matched.item else {
+                        return Err(Error::new(
+                            matched.attributes.span(),
+                            "auto_configure_set expects a SystemSet enum",
+                        ));
+                    };
+                    let args = ConfigureSetArgs::from_attribute(&matched.attributes)?;
+                    ConfigureSetParams::new(&matched.path, item, &args)
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+        let (mut auto_add_systems, add_system_config_macros): (Vec<_>, Vec<_>) =
+            fn_items_with_attribute_macro(
+                items,
+                &[
+                    "auto_add_system",
+                    "auto_on_enter",
+                    "auto_on_exit",
+                    "auto_on_transition",
+                ],
+            )?
+            .into_iter()
+            .map(|matched| {
+                let args = add_system_args_from_attribute(&matched.attributes)?;
+                let config_macro = AddSystemParams::config_macro(&matched.path, &args);
+                Ok((AddSystemParams::new(&matched.path, &args), config_macro))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        let auto_register_one_shots =
+            fn_items_with_attribute_macro(items, &["auto_register_one_shot"])?
+                .into_iter()
+                .map(|matched| {
+                    matched.attributes.meta.require_path_only()?;
+                    let system_io = SystemIo::from_item(&matched.item)?;
+                    Ok(OneShotSystemParams::new(&matched.path, &system_io))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+        let auto_add_observers = fn_items_with_attribute_macro(items, &["auto_add_observer"])?
+            .into_iter()
+            .map(|matched| {
+                matched.attributes.meta.require_path_only()?;
+                Ok(matched.into_path_string())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for config_macro in add_system_config_macros {
+            inject_module(&mut module, || parse2::<Item>(config_macro))?;
+        }
+
+        let plugin_system_set = system_set.then(generate_plugin_system_set);
+        if let Some((system_set, declaration)) = plugin_system_set.clone() {
+            add_systems_in_set(&mut auto_add_systems, &system_set);
+            inject_module(&mut module, || parse2::<Item>(declaration))?;
+        }
+        let plugin_system_set = plugin_system_set.map(|(system_set, _)| system_set);
+
+        if let Some(one_shot_systems_resource) =
+            generate_one_shot_systems_resource(&auto_register_one_shots)?
+        {
+            inject_module(&mut module, || parse2::<Item>(one_shot_systems_resource))?;
+        }
+
         inject_module(&mut module, move || {
+            let auto_register_type_data =
+                generate_register_type_data(&app_param_ident, auto_register_type_data.into_iter())?;
             let auto_register_types =
                 generate_register_types(&app_param_ident, auto_register_types)?;
             let auto_add_events = generate_add_events(&app_param_ident, auto_add_events)?;
             let auto_init_resources =
                 generate_init_resources(&app_param_ident, auto_init_resources)?;
+            let auto_init_non_send_resources =
+                generate_init_non_send_resources(&app_param_ident, auto_init_non_send_resources)?;
+            let auto_init_resources_from_file = generate_init_resources_from_file(
+                &app_param_ident,
+                auto_init_resources_from_file.into_iter(),
+            )?;
+            let auto_insert_resources =
+                generate_insert_resources(&app_param_ident, auto_insert_resources.into_iter())?;
+            let auto_init_states =
+                generate_init_states(&app_param_ident, auto_init_states.into_iter())?;
+            let auto_add_sub_states =
+                generate_add_sub_states(&app_param_ident, auto_add_sub_states)?;
+            let auto_add_computed_states =
+                generate_add_computed_states(&app_param_ident, auto_add_computed_states)?;
+            let auto_init_assets =
+                generate_init_assets(&app_param_ident, auto_init_assets.into_iter())?;
+            let auto_asset_loaders =
+                generate_asset_loaders(&app_param_ident, auto_asset_loaders.into_iter())?;
+            let auto_asset_collections = generate_asset_collections(
+                &app_param_ident,
+                auto_asset_collections,
+                plugin_system_set.as_ref(),
+            )?;
+            let auto_init_gizmo_groups =
+                generate_init_gizmo_groups(&app_param_ident, auto_init_gizmo_groups)?;
+            let auto_insert_gizmo_configs = generate_insert_gizmo_configs(
+                &app_param_ident,
+                auto_insert_gizmo_configs.into_iter(),
+            )?;
+            let auto_register_diagnostics = generate_register_diagnostics(
+                &app_param_ident,
+                auto_register_diagnostics.into_iter(),
+            )?;
             let auto_names = generate_auto_names(&app_param_ident, auto_names)?;
+            let auto_required_components =
+                generate_required_components(&app_param_ident, auto_required_components)?;
+            let auto_state_scoped =
+                generate_state_scoped(&app_param_ident, auto_state_scoped.into_iter())?;
+            let auto_component_hooks =
+                generate_component_hooks(&app_param_ident, auto_component_hooks.into_iter())?;
+            let auto_add_schedules =
+                generate_add_schedules(&app_param_ident, auto_add_schedules.into_iter())?;
+            let auto_configure_sets =
+                generate_configure_sets(&app_param_ident, auto_configure_sets.into_iter())?;
+            let auto_add_systems =
+                generate_add_systems(&app_param_ident, auto_add_systems.into_iter())?;
+            let auto_register_one_shots =
+                generate_register_one_shot_systems(&app_param_ident, &auto_register_one_shots)?;
+            let auto_add_observers =
+                generate_add_observers(&app_param_ident, auto_add_observers.into_iter())?;
             parse2::<Item>(quote! {
                 pub(super) fn #init_name(app: &mut bevy_app::prelude::App) {
                     #auto_register_types
+                    #auto_register_type_data
                     #auto_add_events
                     #auto_init_resources
+                    #auto_init_non_send_resources
+                    #auto_init_resources_from_file
+                    #auto_insert_resources
+                    #auto_init_states
+                    #auto_add_sub_states
+                    #auto_add_computed_states
+                    #auto_init_assets
+                    #auto_asset_loaders
+                    #auto_asset_collections
+                    #auto_init_gizmo_groups
+                    #auto_insert_gizmo_configs
+                    #auto_register_diagnostics
                     #auto_names
+                    #auto_required_components
+                    #auto_state_scoped
+                    #auto_component_hooks
+                    #auto_add_schedules
+                    #auto_configure_sets
+                    #auto_add_systems
+                    #auto_register_one_shots
+                    #auto_add_observers
                 }
             })
         })?;
@@ -125,6 +506,18 @@ fn auto_plugin_inner(mut module: ItemMod, init_name: &Ident) -> Result<MacroStre
     Ok(output)
 }
 
+fn add_system_args_from_attribute(attr: &Attribute) -> Result<AddSystemArgs> {
+    if attr.path().is_ident("auto_on_enter") {
+        Ok(OnStateArgs::from_attribute(attr)?.on_enter())
+    } else if attr.path().is_ident("auto_on_exit") {
+        Ok(OnStateArgs::from_attribute(attr)?.on_exit())
+    } else if attr.path().is_ident("auto_on_transition") {
+        Ok(OnTransitionArgs::from_attribute(attr)?.on_transition())
+    } else {
+        AddSystemArgs::from_attribute(attr)
+    }
+}
+
 /// Automatically registers a type with the Bevy `App`.
 ///
 /// # Example (without generics)
@@ -183,6 +576,112 @@ pub fn auto_register_type(_args: CompilerStream, input: CompilerStream) -> Compi
     // Just return the input unchanged; this acts as a marker.
     input
 }
+
+/// Automatically registers reflect type data for a type in the Bevy `App`, the type itself must be registered.
+///
+/// # Example (without generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_register_type]
+///     #[auto_register_type_data(ReflectDefault)]
+///     #[derive(Component, Reflect, Default)]
+///     #[reflect(Component)]
+///     struct FooComponent;
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.register_type::<FooComponent>();
+///         app.register_type_data::<FooComponent, ReflectDefault>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+///
+/// # Example (with generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_register_type(FooComponentWithGeneric<u32>)]
+///     #[auto_register_type_data(FooComponentWithGeneric<u32>, ReflectDefault)]
+///     #[derive(Component, Reflect, Default)]
+///     #[reflect(Component)]
+///     struct FooComponentWithGeneric<T>(T);
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.register_type::<FooComponentWithGeneric<u32>>();
+///         app.register_type_data::<FooComponentWithGeneric<u32>, ReflectDefault>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_register_type_data(_args: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically registers the `ReflectTrait` type data of a `#[reflect_trait]` trait for the implementing type,
+/// the implementing type must be registered.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[reflect_trait]
+///     trait Ability {
+///         fn name(&self) -> &'static str;
+///     }
+///
+///     #[auto_register_type]
+///     #[derive(Reflect)]
+///     struct Fireball;
+///
+///     #[auto_register_reflect_trait]
+///     impl Ability for Fireball {
+///         fn name(&self) -> &'static str {
+///             "fireball"
+///         }
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.register_type::<Fireball>();
+///         app.register_type_data::<Fireball, ReflectAbility>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_register_reflect_trait(_args: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
 /// Automatically adds an event type to the Bevy `App`.
 ///
 /// # Example (without generics)
@@ -239,6 +738,8 @@ pub fn auto_add_event(_args: CompilerStream, input: CompilerStream) -> CompilerS
 }
 /// Automatically initializes a resource in the Bevy `App`.
 ///
+/// `!Send` types can't implement `Resource`, use `#[auto_init_non_send_resource]` for them instead.
+///
 /// # Example (without generics)
 /// ```no_run
 /// use bevy::prelude::*;
@@ -287,8 +788,145 @@ pub fn auto_add_event(_args: CompilerStream, input: CompilerStream) -> CompilerS
 ///     app.add_plugin(my_plugin::init)
 /// }
 /// ```
+/// # Example (from file)
+/// The `.toml` or `.ron` file is read relative to `CARGO_MANIFEST_DIR`, each format requires the `toml` or `ron` feature.
+/// Syntax errors are compile errors, and a `#[test]` deserializing the file into the resource is emitted next to it so
+/// missing fields and mismatched types fail `cargo test`. The parsers are re-exported by `bevy_auto_plugin`,
+/// only `serde` is needed to derive `Deserialize`.
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_resource(from_file = "assets/config/physics.toml")]
+///     #[derive(Resource, serde::Deserialize)]
+///     struct PhysicsConfig {
+///         gravity: f32,
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.insert_resource::<PhysicsConfig>(
+///             ::bevy_auto_plugin::__private::toml::from_str::<PhysicsConfig>(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", "assets/config/physics.toml")))
+///                 .expect(concat!("assets/config/physics.toml", " does not deserialize into ", stringify!(PhysicsConfig))),
+///         );
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_init_resource(args: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Acts as a marker, `from_file` also emits the file checks next to the resource.
+    let args = MacroStream::from(args);
+    if !is_from_file_args(&args) {
+        return input;
+    }
+    let item = parse_macro_input!(input as Item);
+    // invalid arguments are reported by `auto_plugin`
+    let checks = InitResourceFromFileArgs::from_tokens(args)
+        .and_then(|args| resource_file_checks(&item, &args))
+        .unwrap_or_default();
+    CompilerStream::from(quote! {
+        #item
+        #checks
+    })
+}
+
+/// Automatically initializes a `!Send` resource in the Bevy `App`.
+///
+/// # Example (without generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_non_send_resource]
+///     #[derive(Default)]
+///     struct FooNonSendResource(std::marker::PhantomData<*const ()>);
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_non_send_resource::<FooNonSendResource>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+/// # Example (with generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_non_send_resource(FooNonSendResourceWithGeneric<bool>)]
+///     #[derive(Default)]
+///     struct FooNonSendResourceWithGeneric<T>(std::marker::PhantomData<*const T>);
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_non_send_resource::<FooNonSendResourceWithGeneric<bool>>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_init_non_send_resource(_args: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically inserts a resource built from the provided expression into the Bevy `App`.
+///
+/// Useful for resources without a `Default` or `FromWorld` implementation.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_insert_resource(value = FooResource::new(10))]
+///     #[derive(Resource)]
+///     struct FooResource(usize);
+///
+///     impl FooResource {
+///         fn new(value: usize) -> Self {
+///             Self(value)
+///         }
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.insert_resource::<FooResource>(FooResource::new(10));
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn auto_init_resource(_args: CompilerStream, input: CompilerStream) -> CompilerStream {
+pub fn auto_insert_resource(_args: CompilerStream, input: CompilerStream) -> CompilerStream {
     // Just return the input unchanged; this acts as a marker.
     input
 }
@@ -353,3 +991,1145 @@ pub fn auto_name(_attr: CompilerStream, input: CompilerStream) -> CompilerStream
     // Just return the input unchanged; this acts as a marker.
     input
 }
+
+/// Automatically adds a system to the Bevy `App` in the given schedule.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_add_system(schedule = Update)]
+///     fn foo_system() {}
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.add_systems(Update, foo_system);
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+///
+/// # Example (with ordering)
+/// `before`, `after` and `in_set` can be repeated.
+/// Systems sharing a `chain` group in the same schedule are chained in declaration order.
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+///     struct PhysicsSet;
+///
+///     #[auto_add_system(schedule = Update, in_set = PhysicsSet)]
+///     fn movement() {}
+///
+///     #[auto_add_system(schedule = Update, after = movement, chain = collisions)]
+///     fn detect_collisions() {}
+///
+///     #[auto_add_system(schedule = Update, chain = collisions)]
+///     fn resolve_collisions() {}
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.add_systems(Update, movement.in_set(PhysicsSet));
+///         app.add_systems(Update, (detect_collisions.after(movement), resolve_collisions).chain());
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+///
+/// # Example (with run conditions)
+/// Multiple `run_if` conditions are combined with `and`.
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+///     enum GameState {
+///         #[default]
+///         Playing,
+///     }
+///
+///     #[derive(Resource)]
+///     struct Paused;
+///
+///     #[auto_add_system(schedule = Update, run_if = in_state(GameState::Playing), run_if = not(resource_exists::<Paused>))]
+///     fn foo_system() {}
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.add_systems(Update, foo_system.run_if(in_state(GameState::Playing).and(not(resource_exists::<Paused>))));
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_add_system(_args: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically adds a system to the Bevy `App` in the `OnEnter` schedule of the given state.
+///
+/// Accepts the same ordering and condition arguments as `auto_add_system`.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+///     enum GameState {
+///         #[default]
+///         Menu,
+///     }
+///
+///     #[auto_on_enter(state = GameState::Menu)]
+///     fn spawn_menu() {}
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.add_systems(OnEnter(GameState::Menu), spawn_menu);
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_on_enter(_args: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically adds a system to the Bevy `App` in the `OnExit` schedule of the given state.
+///
+/// Accepts the same ordering and condition arguments as `auto_add_system`.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+///     enum GameState {
+///         #[default]
+///         Menu,
+///     }
+///
+///     #[auto_on_exit(state = GameState::Menu)]
+///     fn despawn_menu() {}
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.add_systems(OnExit(GameState::Menu), despawn_menu);
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_on_exit(_args: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically adds a system to the Bevy `App` in the `OnTransition` schedule of the given states.
+///
+/// Accepts the same ordering and condition arguments as `auto_add_system`.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+///     enum GameState {
+///         #[default]
+///         Menu,
+///         Playing,
+///     }
+///
+///     #[auto_on_transition(exited = GameState::Menu, entered = GameState::Playing)]
+///     fn start_game() {}
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.add_systems(OnTransition { exited: GameState::Menu, entered: GameState::Playing }, start_game);
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_on_transition(_args: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically registers a one-shot system with the Bevy `App` and stores its `SystemId`
+/// in the generated `OneShotSystems` resource, in a field named after the fn.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_register_one_shot]
+///     fn spawn_wave(mut commands: Commands) {}
+///
+///     // code gen:
+///     #[derive(Resource, Debug, Copy, Clone)]
+///     pub struct OneShotSystems {
+///         pub spawn_wave: SystemId<(), ()>,
+///     }
+///
+///     pub(super) fn init(app: &mut App) {
+///         let one_shot_systems = OneShotSystems {
+///             spawn_wave: app.register_system(spawn_wave),
+///         };
+///         app.insert_resource(one_shot_systems);
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+///
+/// fn trigger_wave(mut commands: Commands, one_shot_systems: Res<my_plugin::OneShotSystems>) {
+///     commands.run_system(one_shot_systems.spawn_wave);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_register_one_shot(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically adds a global observer to the Bevy `App`.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[derive(Event)]
+///     struct FooEvent;
+///
+///     #[auto_add_observer]
+///     fn on_foo_event(trigger: Trigger<FooEvent>) {}
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.add_observer(on_foo_event);
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_add_observer(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically initializes a state in the Bevy `App`.
+///
+/// Requires the `StatesPlugin` (included in `DefaultPlugins`) to be added before the plugin.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_state]
+///     #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+///     enum GameState {
+///         #[default]
+///         Menu,
+///         Playing,
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_state::<GameState>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+///
+/// # Example (with initial value)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_state(value = GameState::Playing)]
+///     #[derive(States, Debug, Clone, PartialEq, Eq, Hash)]
+///     enum GameState {
+///         Menu,
+///         Playing,
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.insert_state::<GameState>(GameState::Playing);
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+///
+/// # Example (with state scoped entities)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_state(scoped_entities)]
+///     #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+///     enum GameState {
+///         #[default]
+///         Menu,
+///         Playing,
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_state::<GameState>();
+///         app.enable_state_scoped_entities::<GameState>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_init_state(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically adds a sub state to the Bevy `App`.
+///
+/// The type in the `#[source(...)]` attribute is checked to be a `States` type.
+///
+/// # Example (without generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_state]
+///     #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+///     enum GameState {
+///         #[default]
+///         Menu,
+///         Playing,
+///     }
+///
+///     #[auto_add_sub_state]
+///     #[derive(SubStates, Debug, Default, Clone, PartialEq, Eq, Hash)]
+///     #[source(GameState = GameState::Playing)]
+///     enum PauseState {
+///         #[default]
+///         Running,
+///         Paused,
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_state::<GameState>();
+///         app.add_sub_state::<PauseState>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+///
+/// # Example (with generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_state]
+///     #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+///     enum GameState {
+///         #[default]
+///         Menu,
+///         Playing,
+///     }
+///
+///     #[auto_add_sub_state(FooSubState<bool>)]
+///     #[derive(SubStates, Debug, Default, Clone, PartialEq, Eq, Hash)]
+///     #[source(GameState = GameState::Playing)]
+///     enum FooSubState<T: Send + Sync + Clone + Eq + std::hash::Hash + std::fmt::Debug + 'static> {
+///         #[default]
+///         Foo,
+///         Bar(T),
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_state::<GameState>();
+///         app.add_sub_state::<FooSubState<bool>>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_add_sub_state(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Acts as a marker, also checks the `#[source(...)]` state type.
+    let item = parse_macro_input!(input as Item);
+    match sub_state_source_check(&item) {
+        Ok(source_check) => CompilerStream::from(quote! {
+            #item
+            #source_check
+        }),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Automatically adds a computed state to the Bevy `App`.
+///
+/// # Example (without generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_state]
+///     #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+///     enum GameState {
+///         #[default]
+///         Menu,
+///         Playing,
+///     }
+///
+///     #[auto_add_computed_state]
+///     #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///     struct InGame;
+///
+///     impl ComputedStates for InGame {
+///         type SourceStates = GameState;
+///
+///         fn compute(sources: GameState) -> Option<Self> {
+///             (sources == GameState::Playing).then_some(InGame)
+///         }
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_state::<GameState>();
+///         app.add_computed_state::<InGame>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+///
+/// # Example (with generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_state]
+///     #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+///     enum GameState {
+///         #[default]
+///         Menu,
+///         Playing,
+///     }
+///
+///     #[auto_add_computed_state(InGame<bool>)]
+///     #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///     struct InGame<T>(std::marker::PhantomData<T>);
+///
+///     impl<T: Send + Sync + Clone + Eq + std::hash::Hash + std::fmt::Debug + 'static> ComputedStates for InGame<T> {
+///         type SourceStates = GameState;
+///
+///         fn compute(sources: GameState) -> Option<Self> {
+///             (sources == GameState::Playing).then_some(InGame(std::marker::PhantomData))
+///         }
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_state::<GameState>();
+///         app.add_computed_state::<InGame<bool>>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_add_computed_state(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically makes `StateScoped` a required component, despawning the entity when the state is exited.
+///
+/// Requires `#[auto_init_state(scoped_entities)]` (or `enable_state_scoped_entities`) on the state.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_state(scoped_entities)]
+///     #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+///     enum GameState {
+///         #[default]
+///         Menu,
+///         InGame,
+///     }
+///
+///     #[auto_state_scoped(GameState::InGame)]
+///     #[derive(Component)]
+///     struct Level;
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_state::<GameState>();
+///         app.enable_state_scoped_entities::<GameState>();
+///         app.register_required_components_with::<Level, StateScoped<_>>(|| StateScoped(GameState::InGame));
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_state_scoped(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically registers required components for a component, with an optional constructor expression.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[derive(Component)]
+///     struct Health(usize);
+///
+///     #[auto_require(Transform, Visibility = Visibility::Hidden, Health = Health(100))]
+///     #[derive(Component)]
+///     struct Player;
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.register_required_components::<Player, Transform>();
+///         app.register_required_components_with::<Player, Visibility>(|| Visibility::Hidden);
+///         app.register_required_components_with::<Player, Health>(|| Health(100));
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_require(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically registers component lifecycle hooks for a component.
+///
+/// Registering the same hook twice for a component is a compile error.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::ecs::component::ComponentId;
+/// use bevy::ecs::world::DeferredWorld;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_component_hooks(on_add = foo_on_add, on_remove = foo_on_remove)]
+///     #[derive(Component)]
+///     struct Foo;
+///
+///     fn foo_on_add(world: DeferredWorld, entity: Entity, component_id: ComponentId) {}
+///
+///     fn foo_on_remove(world: DeferredWorld, entity: Entity, component_id: ComponentId) {}
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.world_mut().register_component_hooks::<Foo>().on_add(foo_on_add);
+///         app.world_mut().register_component_hooks::<Foo>().on_remove(foo_on_remove);
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_component_hooks(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically initializes an asset type in the Bevy `App`.
+///
+/// When the asset derives `Reflect`, the asset and its `Handle` are registered for reflection as well.
+///
+/// # Example (without generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_asset]
+///     #[derive(Asset, Reflect)]
+///     struct FooAsset {
+///         value: usize,
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_asset::<FooAsset>();
+///         app.register_asset_reflect::<FooAsset>();
+///         app.register_type::<Handle<FooAsset>>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+///
+/// # Example (with generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_asset(FooAssetWithGeneric<u32>)]
+///     #[derive(Asset, TypePath)]
+///     struct FooAssetWithGeneric<T: Send + Sync + TypePath>(T);
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_asset::<FooAssetWithGeneric<u32>>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_init_asset(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically initializes an asset loader in the Bevy `App`, after the asset types are initialized.
+///
+/// The loader must implement `AssetLoader` and `FromWorld` (or `Default`).
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_asset]
+///     #[derive(Asset, TypePath)]
+///     struct FooAsset(Vec<u8>);
+///
+///     #[auto_init_asset_loader]
+///     #[derive(Default)]
+///     struct FooAssetLoader;
+///
+///     impl AssetLoader for FooAssetLoader {
+///         type Asset = FooAsset;
+///         type Settings = ();
+///         type Error = std::io::Error;
+///
+///         async fn load(
+///             &self,
+///             reader: &mut dyn Reader,
+///             _settings: &(),
+///             _load_context: &mut LoadContext<'_>,
+///         ) -> Result<FooAsset, Self::Error> {
+///             let mut bytes = Vec::new();
+///             reader.read_to_end(&mut bytes).await?;
+///             Ok(FooAsset(bytes))
+///         }
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_asset::<FooAsset>();
+///         app.init_asset_loader::<FooAssetLoader>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_init_asset_loader(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically registers an asset loader built from the provided expression in the Bevy `App`,
+/// after the asset types are initialized.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_asset]
+///     #[derive(Asset, TypePath)]
+///     struct FooAsset(Vec<u8>);
+///
+///     #[auto_register_asset_loader(value = FooAssetLoader { max_size: 1024 })]
+///     struct FooAssetLoader {
+///         max_size: usize,
+///     }
+///
+///     impl AssetLoader for FooAssetLoader {
+///         type Asset = FooAsset;
+///         type Settings = ();
+///         type Error = std::io::Error;
+///
+///         async fn load(
+///             &self,
+///             reader: &mut dyn Reader,
+///             _settings: &(),
+///             _load_context: &mut LoadContext<'_>,
+///         ) -> Result<FooAsset, Self::Error> {
+///             let mut bytes = Vec::new();
+///             reader.read_to_end(&mut bytes).await?;
+///             bytes.truncate(self.max_size);
+///             Ok(FooAsset(bytes))
+///         }
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_asset::<FooAsset>();
+///         app.register_asset_loader::<FooAssetLoader>(FooAssetLoader { max_size: 1024 });
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_register_asset_loader(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically inserts a resource of asset handles at `Startup`, loading each `#[asset(path = "...")]` field
+/// with the `AssetServer`. Fields without `#[asset]` are set to `Default::default()`.
+///
+/// Use the `asset_collection_loaded::<T>()` run condition to wait until every asset of the collection is loaded.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::asset_collection::asset_collection_loaded;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_asset_collection]
+///     #[derive(Resource)]
+///     struct PlayerAssets {
+///         #[asset(path = "textures/player.png")]
+///         sprite: Handle<Image>,
+///         #[asset(path = "audio/jump.ogg")]
+///         jump: Handle<AudioSource>,
+///     }
+///
+///     #[auto_add_system(schedule = Update, run_if = asset_collection_loaded::<PlayerAssets>())]
+///     fn spawn_player(mut commands: Commands, assets: Res<PlayerAssets>) {
+///         commands.spawn(Sprite::from_image(assets.sprite.clone()));
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         // `load_system` inserts `PlayerAssets` with `asset_server.load("...")` for each `#[asset]` field
+///         app.add_systems(Startup, <PlayerAssets as AssetCollection>::load_system());
+///         app.add_systems(Update, spawn_player.run_if(asset_collection_loaded::<PlayerAssets>()));
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_asset_collection(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Acts as a marker, also strips the `#[asset]` field attributes and implements `AssetCollection`.
+    parse_macro_input!(attr as Nothing);
+    let mut item = parse_macro_input!(input as ItemStruct);
+    expand_asset_collection(&mut item)
+        .map(|asset_collection| {
+            CompilerStream::from(quote! {
+                #item
+                #asset_collection
+            })
+        })
+        .unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+/// Automatically configures the variants of a `SystemSet` enum in the given schedule.
+///
+/// `chain` orders the variants in declaration order. `before`, `after` and `run_if` can be repeated and apply to
+/// every variant, multiple `run_if` conditions are combined with `and`. Individual variants accept the same
+/// ordering and condition arguments with `#[configure_set(...)]`.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+///     struct InputSet;
+///
+///     #[auto_configure_set(schedule = Update, chain, run_if = in_state(GameState::Playing))]
+///     #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+///     enum GameplaySet {
+///         #[configure_set(after = InputSet)]
+///         Movement,
+///         Physics,
+///         #[configure_set(run_if = resource_exists::<Score>)]
+///         Scoring,
+///     }
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.configure_sets(
+///             Update,
+///             (
+///                 GameplaySet::Movement.after(InputSet),
+///                 GameplaySet::Physics,
+///                 GameplaySet::Scoring.run_if(resource_exists::<Score>),
+///             )
+///                 .chain()
+///                 .run_if(in_state(GameState::Playing)),
+///         );
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_configure_set(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Acts as a marker, also strips the `#[configure_set]` variant attributes.
+    // non enum items are reported by `auto_plugin`
+    let mut item = parse_macro_input!(input as Item);
+    if let Item::Enum(item) = &mut item {
+        strip_configure_set_attributes(item);
+    }
+    CompilerStream::from(quote! { #item })
+}
+
+/// Automatically initializes a custom schedule in the Bevy `App` and inserts it into `MainScheduleOrder`.
+///
+/// Exactly one of `before` or `after` positions the schedule. `startup` positions it among the startup schedules
+/// and `fixed` in `FixedMainScheduleOrder` instead. `executor` sets the `ExecutorKind` and `ambiguity_detection`
+/// the `LogLevel` of the schedule ambiguity detection.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::ecs::schedule::ScheduleLabel;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_add_schedule(after = Update, executor = SingleThreaded, ambiguity_detection = Warn)]
+///     #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+///     struct Simulation;
+///
+///     #[auto_add_schedule(before = FixedUpdate, fixed)]
+///     #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+///     struct FixedSimulation;
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_schedule(Simulation);
+///         app.world_mut()
+///             .resource_mut::<MainScheduleOrder>()
+///             .insert_after(Update, Simulation);
+///         app.edit_schedule(Simulation, |schedule| {
+///             schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+///             schedule.set_build_settings(ScheduleBuildSettings {
+///                 ambiguity_detection: LogLevel::Warn,
+///                 ..schedule.get_build_settings()
+///             });
+///         });
+///         app.init_schedule(FixedSimulation);
+///         app.world_mut()
+///             .resource_mut::<FixedMainScheduleOrder>()
+///             .insert_before(FixedUpdate, FixedSimulation);
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_add_schedule(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically initializes a custom `GizmoConfigGroup` in the Bevy `App`.
+///
+/// # Example (without generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_gizmo_group]
+///     #[derive(GizmoConfigGroup, Default, Reflect)]
+///     struct DebugGizmos;
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_gizmo_group::<DebugGizmos>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+/// # Example (with generics)
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_gizmo_group(DebugGizmosWithGeneric<bool>)]
+///     #[derive(GizmoConfigGroup, Default, Reflect)]
+///     struct DebugGizmosWithGeneric<T: TypePath + Default + Send + Sync>(T);
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.init_gizmo_group::<DebugGizmosWithGeneric<bool>>();
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+/// # Example (with config)
+/// `config` inserts the group with a non-default `GizmoConfig`, generic groups pass their generics with
+/// `generics(...)`, e.g. `#[auto_init_gizmo_group(config = GizmoConfig::default(), generics(bool))]`.
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_init_gizmo_group(config = GizmoConfig { line_width: 4.0, ..default() })]
+///     #[derive(GizmoConfigGroup, Default, Reflect)]
+///     struct DebugGizmos;
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.insert_gizmo_config(DebugGizmos::default(), GizmoConfig { line_width: 4.0, ..default() });
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_init_gizmo_group(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Just return the input unchanged; this acts as a marker.
+    input
+}
+
+/// Automatically registers a `Diagnostic` for a `DiagnosticPath` constant in the Bevy `App`.
+///
+/// `max_history` sets the history length and `suffix` the unit suffix of the diagnostic.
+///
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::diagnostic::{Diagnostic, DiagnosticPath, RegisterDiagnostic};
+/// use bevy_auto_plugin::auto_plugin_module::*;
+///
+/// #[auto_plugin(init_name=init)]
+/// pub mod my_plugin {
+///     use super::*;
+///
+///     #[auto_register_diagnostic(max_history = 20, suffix = "ms")]
+///     pub const PATHFINDING_TIME: DiagnosticPath = DiagnosticPath::const_new("ai/pathfinding_time");
+///
+///     // code gen:
+///     pub(super) fn init(app: &mut App) {
+///         app.register_diagnostic(
+///             Diagnostic::new(PATHFINDING_TIME)
+///                 .with_max_history_length(20)
+///                 .with_suffix("ms"),
+///         );
+///     }
+/// }
+///
+/// fn plugin(app: &mut App) {
+///     app.add_plugin(my_plugin::init)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn auto_register_diagnostic(_attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    // Acts as a marker, only checks that it is applied to a const item.
+    let cloned_input = input.clone();
+    parse_macro_input!(input as ItemConst);
+    cloned_input
+}
@@ -0,0 +1,657 @@
+use crate::resource_file::check_resource_file;
+use crate::util::{resolve_path_from_item, resolve_path_from_item_with_generics};
+use proc_macro2::{Ident, Span, TokenStream as MacroStream, TokenTree};
+use syn::meta::ParseNestedMeta;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{parse_quote, Attribute, Error, Expr, Item, LitStr, Meta, Path, Token, Type};
+
+fn starts_with_ident(tokens: &MacroStream, name: &str) -> bool {
+    matches!(tokens.clone().into_iter().next(), Some(TokenTree::Ident(ident)) if ident == name)
+}
+
+/// `#[auto_init_resource(from_file = "...")]` shares its name with the path argument form `#[auto_init_resource(Foo<T>)]`
+pub fn is_from_file_args(tokens: &MacroStream) -> bool {
+    starts_with_ident(tokens, "from_file")
+}
+
+/// `#[auto_init_gizmo_group(config = ..., generics(...))]` shares its name with the path argument form `#[auto_init_gizmo_group(Foo<T>)]`
+pub fn is_gizmo_config_args(tokens: &MacroStream) -> bool {
+    starts_with_ident(tokens, "config") || starts_with_ident(tokens, "generics")
+}
+
+/// Arguments of an attribute parsed as `#[attribute(key = value, flag, ...)]`
+pub trait AttributeArgs: Default {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()>;
+
+    /// Called after all arguments have been parsed to check for missing or conflicting arguments
+    fn validate(&self, _span: Span) -> syn::Result<()> {
+        Ok(())
+    }
+
+    fn from_attribute(attr: &Attribute) -> syn::Result<Self> {
+        let mut args = Self::default();
+        // allow #[attribute] without args
+        if !matches!(attr.meta, Meta::Path(_)) {
+            attr.parse_nested_meta(|meta| args.parse_meta(meta))?;
+        }
+        args.validate(attr.span())?;
+        Ok(args)
+    }
+
+    fn from_tokens(tokens: MacroStream) -> syn::Result<Self> {
+        let mut args = Self::default();
+        syn::meta::parser(|meta| args.parse_meta(meta)).parse2(tokens)?;
+        args.validate(Span::call_site())?;
+        Ok(args)
+    }
+
+    /// Path of the annotated struct or enum, generic items need their generics from the arguments
+    fn resolve_path(&self, item: &Item) -> syn::Result<Path> {
+        resolve_path_from_item(item)
+    }
+}
+
+/// `#[auto_add_system(schedule = Update, before = foo, after = bar, in_set = FooSet, chain = foo_chain, run_if = foo_condition)]`
+///
+/// `before`, `after`, `in_set` and `run_if` can be repeated, multiple `run_if` conditions are combined with `and`. Systems sharing the same `chain` group and schedule
+/// are added together and chained in declaration order.
+#[derive(Default)]
+pub struct AddSystemArgs {
+    pub schedule: Option<Expr>,
+    pub before: Vec<Expr>,
+    pub after: Vec<Expr>,
+    pub in_set: Vec<Expr>,
+    pub chain: Option<Ident>,
+    pub run_if: Vec<Expr>,
+}
+
+impl AttributeArgs for AddSystemArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("schedule") {
+            self.schedule = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("before") {
+            self.before.push(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("after") {
+            self.after.push(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("in_set") {
+            self.in_set.push(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("chain") {
+            if self.chain.is_some() {
+                return Err(meta.error("chain group already specified"));
+            }
+            self.chain = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("run_if") {
+            self.run_if.push(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute"))
+        }
+    }
+
+    fn validate(&self, span: Span) -> syn::Result<()> {
+        if self.schedule.is_none() {
+            return Err(Error::new(
+                span,
+                "auto_add_system requires a schedule. Example: #[auto_add_system(schedule = Update)]",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Parses the [`AddSystemArgs`] ordering and condition arguments for attributes that imply the schedule
+fn parse_implied_schedule_system_meta(
+    system: &mut AddSystemArgs,
+    meta: ParseNestedMeta,
+) -> syn::Result<()> {
+    if meta.path.is_ident("schedule") {
+        return Err(meta.error("schedule is implied by the attribute"));
+    }
+    system.parse_meta(meta)
+}
+
+/// `#[auto_on_enter(state = GameState::Menu)]` and `#[auto_on_exit(state = GameState::Menu)]`
+///
+/// Accepts the same arguments as [`AddSystemArgs`] except `schedule`.
+#[derive(Default)]
+pub struct OnStateArgs {
+    pub state: Option<Expr>,
+    pub system: AddSystemArgs,
+}
+
+impl OnStateArgs {
+    pub fn on_enter(self) -> AddSystemArgs {
+        let state = self.state;
+        AddSystemArgs {
+            schedule: Some(parse_quote!(bevy_state::state::OnEnter(#state))),
+            ..self.system
+        }
+    }
+
+    pub fn on_exit(self) -> AddSystemArgs {
+        let state = self.state;
+        AddSystemArgs {
+            schedule: Some(parse_quote!(bevy_state::state::OnExit(#state))),
+            ..self.system
+        }
+    }
+}
+
+impl AttributeArgs for OnStateArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("state") {
+            self.state = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            parse_implied_schedule_system_meta(&mut self.system, meta)
+        }
+    }
+
+    fn validate(&self, span: Span) -> syn::Result<()> {
+        if self.state.is_none() {
+            return Err(Error::new(
+                span,
+                "expected a state. Example: #[auto_on_enter(state = GameState::Menu)]",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `#[auto_on_transition(exited = GameState::Menu, entered = GameState::Playing)]`
+///
+/// Accepts the same arguments as [`AddSystemArgs`] except `schedule`.
+#[derive(Default)]
+pub struct OnTransitionArgs {
+    pub exited: Option<Expr>,
+    pub entered: Option<Expr>,
+    pub system: AddSystemArgs,
+}
+
+impl OnTransitionArgs {
+    pub fn on_transition(self) -> AddSystemArgs {
+        let exited = self.exited;
+        let entered = self.entered;
+        AddSystemArgs {
+            schedule: Some(parse_quote!(bevy_state::state::OnTransition {
+                exited: #exited,
+                entered: #entered,
+            })),
+            ..self.system
+        }
+    }
+}
+
+impl AttributeArgs for OnTransitionArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("exited") {
+            self.exited = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("entered") {
+            self.entered = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            parse_implied_schedule_system_meta(&mut self.system, meta)
+        }
+    }
+
+    fn validate(&self, span: Span) -> syn::Result<()> {
+        if self.exited.is_none() || self.entered.is_none() {
+            return Err(Error::new(
+                span,
+                "expected exited and entered states. Example: #[auto_on_transition(exited = GameState::Menu, entered = GameState::Playing)]",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `#[auto_init_resource(from_file = "assets/config/physics.toml")]`
+///
+/// The `.toml` or `.ron` path is relative to `CARGO_MANIFEST_DIR`, the file is parsed at compile time so syntax errors
+/// are reported as compile errors.
+#[derive(Default)]
+pub struct InitResourceFromFileArgs {
+    pub from_file: Option<LitStr>,
+}
+
+impl AttributeArgs for InitResourceFromFileArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("from_file") {
+            self.from_file = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute"))
+        }
+    }
+
+    fn validate(&self, span: Span) -> syn::Result<()> {
+        let Some(from_file) = &self.from_file else {
+            return Err(Error::new(
+                span,
+                "expected a file. Example: #[auto_init_resource(from_file = \"assets/config.toml\")]",
+            ));
+        };
+        check_resource_file(from_file).map(drop)
+    }
+}
+
+/// `#[asset(path = "textures/player.png")]` on `#[auto_asset_collection]` fields
+#[derive(Default)]
+pub struct AssetFieldArgs {
+    pub path: Option<LitStr>,
+}
+
+impl AttributeArgs for AssetFieldArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("path") {
+            self.path = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute"))
+        }
+    }
+
+    fn validate(&self, span: Span) -> syn::Result<()> {
+        if self.path.is_none() {
+            return Err(Error::new(
+                span,
+                "expected a path. Example: #[asset(path = \"textures/player.png\")]",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `#[auto_init_gizmo_group(config = GizmoConfig { line_width: 4.0, ..default() })]`
+///
+/// Generic groups pass their generics with `generics(...)`, e.g. `#[auto_init_gizmo_group(config = GizmoConfig::default(), generics(bool))]`
+#[derive(Default)]
+pub struct GizmoConfigArgs {
+    pub config: Option<Expr>,
+    pub generics: Option<Punctuated<Type, Token![,]>>,
+}
+
+impl AttributeArgs for GizmoConfigArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("config") {
+            self.config = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("generics") {
+            let content;
+            syn::parenthesized!(content in meta.input);
+            self.generics = Some(content.parse_terminated(Type::parse, Token![,])?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute"))
+        }
+    }
+
+    fn validate(&self, span: Span) -> syn::Result<()> {
+        if self.config.is_none() {
+            return Err(Error::new(
+                span,
+                "expected a config. Example: #[auto_init_gizmo_group(config = GizmoConfig::default())]",
+            ));
+        }
+        Ok(())
+    }
+
+    fn resolve_path(&self, item: &Item) -> syn::Result<Path> {
+        resolve_path_from_item_with_generics(item, self.generics.as_ref())
+    }
+}
+
+/// `#[auto_register_diagnostic]` or `#[auto_register_diagnostic(max_history = 20, suffix = "ms")]`
+#[derive(Default)]
+pub struct RegisterDiagnosticArgs {
+    pub max_history: Option<Expr>,
+    pub suffix: Option<Expr>,
+}
+
+impl AttributeArgs for RegisterDiagnosticArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("max_history") {
+            self.max_history = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("suffix") {
+            self.suffix = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute"))
+        }
+    }
+}
+
+/// `#[auto_insert_resource(value = FooResource::new(1))]`
+#[derive(Default)]
+pub struct InsertResourceArgs {
+    pub value: Option<Expr>,
+}
+
+impl AttributeArgs for InsertResourceArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("value") {
+            self.value = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute"))
+        }
+    }
+
+    fn validate(&self, span: Span) -> syn::Result<()> {
+        if self.value.is_none() {
+            return Err(Error::new(
+                span,
+                "expected a value. Example: #[auto_insert_resource(value = FooResource::new(1))]",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `#[auto_register_asset_loader(value = FooAssetLoader::new(1))]`
+#[derive(Default)]
+pub struct RegisterAssetLoaderArgs {
+    pub value: Option<Expr>,
+}
+
+impl AttributeArgs for RegisterAssetLoaderArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("value") {
+            self.value = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute"))
+        }
+    }
+
+    fn validate(&self, span: Span) -> syn::Result<()> {
+        if self.value.is_none() {
+            return Err(Error::new(
+                span,
+                "expected a value. Example: #[auto_register_asset_loader(value = FooAssetLoader::new(1))]",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `#[auto_add_schedule(after = Update, executor = SingleThreaded, ambiguity_detection = Warn)]`
+///
+/// Exactly one of `before` or `after` positions the schedule in `MainScheduleOrder`. `startup` positions it among
+/// the startup schedules and `fixed` in `FixedMainScheduleOrder` instead.
+#[derive(Default)]
+pub struct AddScheduleArgs {
+    pub before: Option<Expr>,
+    pub after: Option<Expr>,
+    pub startup: bool,
+    pub fixed: bool,
+    pub executor: Option<Ident>,
+    pub ambiguity_detection: Option<Ident>,
+}
+
+impl AttributeArgs for AddScheduleArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        fn parse_variant(meta: &ParseNestedMeta, variants: &[&str]) -> syn::Result<Ident> {
+            let ident: Ident = meta.value()?.parse()?;
+            if !variants.iter().any(|variant| ident == variant) {
+                return Err(Error::new(
+                    ident.span(),
+                    format!("expected one of: {}", variants.join(", ")),
+                ));
+            }
+            Ok(ident)
+        }
+        if meta.path.is_ident("before") {
+            self.before = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("after") {
+            self.after = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("startup") {
+            self.startup = true;
+            Ok(())
+        } else if meta.path.is_ident("fixed") {
+            self.fixed = true;
+            Ok(())
+        } else if meta.path.is_ident("executor") {
+            self.executor = Some(parse_variant(
+                &meta,
+                &["SingleThreaded", "Simple", "MultiThreaded"],
+            )?);
+            Ok(())
+        } else if meta.path.is_ident("ambiguity_detection") {
+            self.ambiguity_detection = Some(parse_variant(&meta, &["Ignore", "Warn", "Error"])?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute"))
+        }
+    }
+
+    fn validate(&self, span: Span) -> syn::Result<()> {
+        if self.before.is_some() == self.after.is_some() {
+            return Err(Error::new(
+                span,
+                "expected either before or after. Example: #[auto_add_schedule(after = Update)]",
+            ));
+        }
+        if self.startup && self.fixed {
+            return Err(Error::new(span, "startup and fixed are mutually exclusive"));
+        }
+        Ok(())
+    }
+}
+
+/// `#[auto_configure_set(schedule = Update, chain, before = FooSet, after = BarSet, run_if = foo_condition)]`
+///
+/// `chain` orders the variants in declaration order. The remaining arguments are the same as [`SetConfigArgs`]
+/// and apply to all the variants.
+#[derive(Default)]
+pub struct ConfigureSetArgs {
+    pub schedule: Option<Expr>,
+    pub chain: bool,
+    pub config: SetConfigArgs,
+}
+
+impl AttributeArgs for ConfigureSetArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("schedule") {
+            self.schedule = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("chain") {
+            self.chain = true;
+            Ok(())
+        } else {
+            self.config.parse_meta(meta)
+        }
+    }
+
+    fn validate(&self, span: Span) -> syn::Result<()> {
+        if self.schedule.is_none() {
+            return Err(Error::new(
+                span,
+                "auto_configure_set requires a schedule. Example: #[auto_configure_set(schedule = Update)]",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `#[configure_set(before = FooSet, after = BarSet, run_if = foo_condition)]` on `#[auto_configure_set]` enum variants
+///
+/// `before`, `after` and `run_if` can be repeated, multiple `run_if` conditions are combined with `and`.
+#[derive(Default)]
+pub struct SetConfigArgs {
+    pub before: Vec<Expr>,
+    pub after: Vec<Expr>,
+    pub run_if: Vec<Expr>,
+}
+
+impl AttributeArgs for SetConfigArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("before") {
+            self.before.push(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("after") {
+            self.after.push(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("run_if") {
+            self.run_if.push(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute"))
+        }
+    }
+}
+
+/// `#[auto_init_state]` or `#[auto_init_state(value = GameState::Playing, scoped_entities)]`
+#[derive(Default)]
+pub struct InitStateArgs {
+    pub value: Option<Expr>,
+    pub scoped_entities: bool,
+}
+
+impl AttributeArgs for InitStateArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("value") {
+            self.value = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("scoped_entities") {
+            self.scoped_entities = true;
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute"))
+        }
+    }
+}
+
+/// `#[auto_component_hooks(on_add = foo_on_add, on_insert = foo_on_insert, on_replace = foo_on_replace, on_remove = foo_on_remove)]`
+#[derive(Default)]
+pub struct ComponentHooksArgs {
+    pub on_add: Option<Expr>,
+    pub on_insert: Option<Expr>,
+    pub on_replace: Option<Expr>,
+    pub on_remove: Option<Expr>,
+}
+
+impl ComponentHooksArgs {
+    /// `(hook name, hook fn)` pairs of the provided hooks
+    pub fn hooks(&self) -> impl Iterator<Item = (&'static str, &Expr)> {
+        [
+            ("on_add", &self.on_add),
+            ("on_insert", &self.on_insert),
+            ("on_replace", &self.on_replace),
+            ("on_remove", &self.on_remove),
+        ]
+        .into_iter()
+        .filter_map(|(hook, function)| Some((hook, function.as_ref()?)))
+    }
+}
+
+impl AttributeArgs for ComponentHooksArgs {
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        let hook = if meta.path.is_ident("on_add") {
+            &mut self.on_add
+        } else if meta.path.is_ident("on_insert") {
+            &mut self.on_insert
+        } else if meta.path.is_ident("on_replace") {
+            &mut self.on_replace
+        } else if meta.path.is_ident("on_remove") {
+            &mut self.on_remove
+        } else {
+            return Err(meta.error("unsupported attribute"));
+        };
+        if hook.is_some() {
+            return Err(meta.error("hook already specified"));
+        }
+        *hook = Some(meta.value()?.parse()?);
+        Ok(())
+    }
+
+    fn validate(&self, span: Span) -> syn::Result<()> {
+        if self.hooks().next().is_none() {
+            return Err(Error::new(
+                span,
+                "expected at least one hook. Example: #[auto_component_hooks(on_add = foo_on_add)]",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `#[auto_state_scoped(GameState::InGame)]`
+pub struct StateScopedArgs {
+    pub state: Expr,
+}
+
+impl Parse for StateScopedArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            state: input.parse()?,
+        })
+    }
+}
+
+/// A single `Component` or `Component = constructor` entry of [`RequireArgs`]
+pub struct RequiredComponentArg {
+    pub component: Type,
+    pub constructor: Option<Expr>,
+}
+
+impl Parse for RequiredComponentArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let component = input.parse()?;
+        let constructor = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self {
+            component,
+            constructor,
+        })
+    }
+}
+
+/// `#[auto_require(Transform, Visibility = Visibility::Hidden)]`
+pub struct RequireArgs {
+    pub requires: Vec<RequiredComponentArg>,
+}
+
+impl Parse for RequireArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let requires = Punctuated::<RequiredComponentArg, Token![,]>::parse_terminated(input)?;
+        if requires.is_empty() {
+            return Err(input.error(
+                "expected at least one component. Example: #[auto_require(Transform, Visibility = Visibility::Hidden)]",
+            ));
+        }
+        Ok(Self {
+            requires: requires.into_iter().collect(),
+        })
+    }
+}
+
+/// `#[auto_register_type_data(ReflectDefault, ReflectSerialize)]` or `#[auto_register_type_data(Foo<u32>, ReflectDefault)]`
+///
+/// A leading path matching the item's ident is the generic instantiation, like `#[auto_register_type(Foo<u32>)]`.
+pub struct RegisterTypeDataArgs {
+    pub paths: Punctuated<Path, Token![,]>,
+}
+
+impl Parse for RegisterTypeDataArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            paths: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
@@ -1,17 +1,543 @@
-use crate::util::path_to_string;
-use proc_macro2::{Ident, TokenStream as MacroStream};
-use quote::quote;
+use crate::attribute_args::{
+    AddScheduleArgs, AddSystemArgs, AttributeArgs, ComponentHooksArgs, ConfigureSetArgs,
+    GizmoConfigArgs, InitResourceFromFileArgs, InitStateArgs, InsertResourceArgs,
+    RegisterAssetLoaderArgs, RegisterDiagnosticArgs, RequireArgs, SetConfigArgs, StateScopedArgs,
+};
+use crate::resource_file::ResourceFileFormat;
+use crate::util::{path_to_string, SystemIo};
+use proc_macro2::{Ident, Span, TokenStream as MacroStream};
+use quote::{format_ident, quote, ToTokens};
 use std::collections::HashSet;
-use syn::Path;
+use syn::spanned::Spanned;
+use syn::{Expr, Fields, ItemEnum, LitStr, Path, Type};
 
+pub mod attribute_args;
+pub mod resource_file;
 pub mod util;
 
 #[derive(Default)]
 pub struct AutoPluginContext {
     pub register_types: HashSet<String>,
+    pub register_type_data: HashSet<TypeDataParams>,
     pub add_events: HashSet<String>,
     pub init_resources: HashSet<String>,
+    pub init_non_send_resources: HashSet<String>,
+    pub insert_resources: HashSet<InsertResourceParams>,
+    pub init_resources_from_file: HashSet<InitResourceFromFileParams>,
     pub auto_names: HashSet<String>,
+    // kept in declaration order
+    pub add_systems: Vec<AddSystemParams>,
+    // kept in declaration order
+    pub configure_sets: Vec<ConfigureSetParams>,
+    // kept in declaration order, schedules inserted at the same position keep their relative order
+    pub add_schedules: Vec<AddScheduleParams>,
+    // kept in declaration order
+    pub one_shot_systems: Vec<OneShotSystemParams>,
+    pub add_observers: HashSet<String>,
+    pub init_states: HashSet<InitStateParams>,
+    pub add_sub_states: HashSet<String>,
+    pub add_computed_states: HashSet<String>,
+    pub state_scoped: HashSet<StateScopedParams>,
+    pub init_assets: HashSet<InitAssetParams>,
+    // kept in declaration order
+    pub asset_loaders: Vec<AssetLoaderParams>,
+    pub asset_collections: HashSet<String>,
+    pub init_gizmo_groups: HashSet<String>,
+    pub insert_gizmo_configs: HashSet<GizmoConfigParams>,
+    pub register_diagnostics: HashSet<DiagnosticParams>,
+    pub required_components: HashSet<RequiredComponentParams>,
+    // kept in declaration order
+    pub component_hooks: Vec<ComponentHookParams>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeDataParams {
+    pub ty: String,
+    pub type_data: String,
+}
+
+impl TypeDataParams {
+    pub fn new(ty: &Path, type_data: &Path) -> Self {
+        Self {
+            ty: path_to_string(ty, false),
+            type_data: path_to_string(type_data, false),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AddSystemParams {
+    pub system: String,
+    pub schedule: String,
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+    pub in_set: Vec<String>,
+    pub chain: Option<String>,
+    pub run_if: Vec<String>,
+    /// set added by `auto_plugin(system_set)`, see [`add_systems_in_set`]
+    pub plugin_set: Option<String>,
+}
+
+fn to_strings(exprs: &[Expr]) -> Vec<String> {
+    exprs
+        .iter()
+        .map(|expr| expr.to_token_stream().to_string())
+        .collect()
+}
+
+fn parse_exprs(items: &[String]) -> syn::Result<Vec<Expr>> {
+    items
+        .iter()
+        .map(|item| syn::parse_str::<Expr>(item))
+        .collect()
+}
+
+/// `a.and(b)` for `run_if` conditions, `None` without conditions
+fn run_if_condition(run_if: &[Expr]) -> Option<MacroStream> {
+    let (condition, rest) = run_if.split_first()?;
+    Some(rest.iter().fold(quote! { #condition }, |condition, rest| {
+        quote! { bevy_ecs::schedule::Condition::and(#condition, #rest) }
+    }))
+}
+
+/// `IntoSystemConfigs::method(configs, arg)` for each argument, does not require the trait to be in scope
+fn system_configs_call<T: ToTokens>(
+    configs: MacroStream,
+    method: &str,
+    args: impl IntoIterator<Item = T>,
+) -> MacroStream {
+    let method = format_ident!("{}", method);
+    args.into_iter().fold(configs, |configs, arg| {
+        quote! { bevy_ecs::schedule::IntoSystemConfigs::#method(#configs, #arg) }
+    })
+}
+
+/// `IntoSystemSetConfigs::method(configs, arg)` for each argument, does not require the trait to be in scope
+fn system_set_configs_call<T: ToTokens>(
+    configs: MacroStream,
+    method: &str,
+    args: impl IntoIterator<Item = T>,
+) -> MacroStream {
+    let method = format_ident!("{}", method);
+    args.into_iter().fold(configs, |configs, arg| {
+        quote! { bevy_ecs::schedule::IntoSystemSetConfigs::#method(#configs, #arg) }
+    })
+}
+
+impl AddSystemParams {
+    pub fn new(system: &Path, args: &AddSystemArgs) -> Self {
+        let schedule = args
+            .schedule
+            .as_ref()
+            .map(|schedule| schedule.to_token_stream().to_string())
+            .unwrap_or_else(|| unreachable!("schedule is validated when parsing"));
+        Self {
+            system: path_to_string(system, false),
+            schedule,
+            before: to_strings(&args.before),
+            after: to_strings(&args.after),
+            in_set: to_strings(&args.in_set),
+            chain: args.chain.as_ref().map(ToString::to_string),
+            run_if: to_strings(&args.run_if),
+            plugin_set: None,
+        }
+    }
+
+    /// Name of the macro declared by [`AddSystemParams::config_macro`], derived from the attribute arguments
+    fn config_macro_ident(&self) -> Ident {
+        // FNV-1a, stable across compiler versions unlike `DefaultHasher`
+        let key = format!(
+            "{:?}",
+            (
+                &self.schedule,
+                &self.before,
+                &self.after,
+                &self.in_set,
+                &self.chain,
+                &self.run_if,
+            )
+        );
+        let hash = key.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+        });
+        let system = self.system.replace(|c: char| !c.is_alphanumeric(), "_");
+        format_ident!("__auto_add_system_{}_{:016x}", system, hash)
+    }
+
+    /// Declares a macro next to the system expanding to the schedule and the system config
+    ///
+    /// The generated `add_systems` call expands it instead of reparsing the arguments, so unresolved names
+    /// keep the span of the attribute argument and are reported once.
+    pub fn config_macro(system: &Path, args: &AddSystemArgs) -> MacroStream {
+        let ident = Self::new(system, args).config_macro_ident();
+        let schedule = &args.schedule;
+        let config = system_configs_call(quote! { #system }, "before", &args.before);
+        let config = system_configs_call(config, "after", &args.after);
+        let config = system_configs_call(config, "in_set", &args.in_set);
+        let config = system_configs_call(config, "run_if", run_if_condition(&args.run_if));
+        quote! {
+            #[doc(hidden)]
+            #[allow(unused_macros)]
+            macro_rules! #ident {
+                (schedule) => { #schedule };
+                (config) => { #config };
+            }
+        }
+    }
+
+    /// Expands the config macro, in the plugin `SystemSet` with `auto_plugin(system_set)`
+    fn system_config(&self) -> syn::Result<MacroStream> {
+        let ident = self.config_macro_ident();
+        let plugin_set = self
+            .plugin_set
+            .as_deref()
+            .map(syn::parse_str::<Path>)
+            .transpose()?;
+        Ok(system_configs_call(
+            quote! { #ident!(config) },
+            "in_set",
+            plugin_set,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AddScheduleParams {
+    pub label: String,
+    /// schedule the label is inserted next to
+    pub anchor: String,
+    pub before: bool,
+    pub startup: bool,
+    pub fixed: bool,
+    pub executor: Option<String>,
+    pub ambiguity_detection: Option<String>,
+}
+
+impl AddScheduleParams {
+    pub fn new(label: &Path, args: &AddScheduleArgs) -> Self {
+        let anchor = args
+            .before
+            .as_ref()
+            .or(args.after.as_ref())
+            .map(|anchor| anchor.to_token_stream().to_string())
+            .unwrap_or_else(|| unreachable!("before or after is validated when parsing"));
+        Self {
+            label: path_to_string(label, false),
+            anchor,
+            before: args.before.is_some(),
+            startup: args.startup,
+            fixed: args.fixed,
+            executor: args.executor.as_ref().map(ToString::to_string),
+            ambiguity_detection: args.ambiguity_detection.as_ref().map(ToString::to_string),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SetConfigParams {
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+    pub run_if: Vec<String>,
+}
+
+impl SetConfigParams {
+    pub fn new(args: &SetConfigArgs) -> Self {
+        Self {
+            before: to_strings(&args.before),
+            after: to_strings(&args.after),
+            run_if: to_strings(&args.run_if),
+        }
+    }
+
+    /// `configs.before(..).after(..).run_if(..)`
+    fn config(&self, configs: MacroStream) -> syn::Result<MacroStream> {
+        let configs = system_set_configs_call(configs, "before", parse_exprs(&self.before)?);
+        let configs = system_set_configs_call(configs, "after", parse_exprs(&self.after)?);
+        let run_if = run_if_condition(&parse_exprs(&self.run_if)?);
+        Ok(system_set_configs_call(configs, "run_if", run_if))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConfigureSetParams {
+    pub set: String,
+    pub schedule: String,
+    pub chain: bool,
+    pub config: SetConfigParams,
+    /// unit variants in declaration order with their `#[configure_set(...)]` config
+    pub variants: Vec<(String, SetConfigParams)>,
+}
+
+impl ConfigureSetParams {
+    pub fn new(set: &Path, item: &ItemEnum, args: &ConfigureSetArgs) -> syn::Result<Self> {
+        if item.variants.is_empty() {
+            return Err(syn::Error::new(
+                item.ident.span(),
+                "auto_configure_set expects at least one variant",
+            ));
+        }
+        let mut variants = vec![];
+        for variant in &item.variants {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    "auto_configure_set only supports unit variants",
+                ));
+            }
+            let mut config = SetConfigArgs::default();
+            for attr in &variant.attrs {
+                if attr.path().is_ident("configure_set") {
+                    attr.parse_nested_meta(|meta| config.parse_meta(meta))?;
+                }
+            }
+            variants.push((variant.ident.to_string(), SetConfigParams::new(&config)));
+        }
+        let schedule = args
+            .schedule
+            .as_ref()
+            .map(|schedule| schedule.to_token_stream().to_string())
+            .unwrap_or_else(|| unreachable!("schedule is validated when parsing"));
+        Ok(Self {
+            set: path_to_string(set, false),
+            schedule,
+            chain: args.chain,
+            config: SetConfigParams::new(&args.config),
+            variants,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OneShotSystemParams {
+    pub system: String,
+    pub input: String,
+    pub output: String,
+}
+
+impl OneShotSystemParams {
+    pub fn new(system: &Path, system_io: &SystemIo) -> Self {
+        Self {
+            system: path_to_string(system, false),
+            input: system_io.input.to_token_stream().to_string(),
+            output: system_io.output.to_token_stream().to_string(),
+        }
+    }
+
+    fn field_ident(&self) -> syn::Result<Ident> {
+        let system = syn::parse_str::<Path>(&self.system)?;
+        system
+            .get_ident()
+            .cloned()
+            .ok_or_else(|| syn::Error::new(system.span(), "expected fn ident"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InsertResourceParams {
+    pub resource: String,
+    pub value: String,
+}
+
+impl InsertResourceParams {
+    pub fn new(resource: &Path, args: &InsertResourceArgs) -> Self {
+        Self {
+            resource: path_to_string(resource, false),
+            value: args.value.to_token_stream().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiagnosticParams {
+    pub path: String,
+    pub max_history: Option<String>,
+    pub suffix: Option<String>,
+}
+
+impl DiagnosticParams {
+    pub fn new(path: &Path, args: &RegisterDiagnosticArgs) -> Self {
+        Self {
+            path: path_to_string(path, false),
+            max_history: args
+                .max_history
+                .as_ref()
+                .map(|max_history| max_history.to_token_stream().to_string()),
+            suffix: args
+                .suffix
+                .as_ref()
+                .map(|suffix| suffix.to_token_stream().to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GizmoConfigParams {
+    pub group: String,
+    pub config: String,
+}
+
+impl GizmoConfigParams {
+    pub fn new(group: &Path, args: &GizmoConfigArgs) -> Self {
+        Self {
+            group: path_to_string(group, false),
+            config: args.config.to_token_stream().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InitResourceFromFileParams {
+    pub resource: String,
+    pub from_file: String,
+}
+
+impl InitResourceFromFileParams {
+    pub fn new(resource: &Path, args: &InitResourceFromFileArgs) -> Self {
+        Self {
+            resource: path_to_string(resource, false),
+            from_file: args
+                .from_file
+                .as_ref()
+                .map(LitStr::value)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InitStateParams {
+    pub state: String,
+    pub value: Option<String>,
+    pub scoped_entities: bool,
+}
+
+impl InitStateParams {
+    pub fn new(state: &Path, args: &InitStateArgs) -> Self {
+        Self {
+            state: path_to_string(state, false),
+            value: args
+                .value
+                .as_ref()
+                .map(|value| value.to_token_stream().to_string()),
+            scoped_entities: args.scoped_entities,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RequiredComponentParams {
+    pub component: String,
+    pub required: String,
+    pub constructor: Option<String>,
+}
+
+impl RequiredComponentParams {
+    pub fn from_args(component: &Path, args: &RequireArgs) -> Vec<Self> {
+        args.requires
+            .iter()
+            .map(|require| Self {
+                component: path_to_string(component, false),
+                required: require.component.to_token_stream().to_string(),
+                constructor: require
+                    .constructor
+                    .as_ref()
+                    .map(|constructor| constructor.to_token_stream().to_string()),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ComponentHookParams {
+    pub component: String,
+    pub hook: String,
+    pub function: String,
+}
+
+impl ComponentHookParams {
+    pub fn from_args(component: &Path, args: &ComponentHooksArgs) -> Vec<Self> {
+        args.hooks()
+            .map(|(hook, function)| Self {
+                component: path_to_string(component, false),
+                hook: hook.to_string(),
+                function: function.to_token_stream().to_string(),
+            })
+            .collect()
+    }
+}
+
+/// Returns `false` without inserting anything if one of the hooks is already registered for its component,
+/// bevy panics when a component hook is registered twice
+pub fn insert_component_hooks(
+    component_hooks: &mut Vec<ComponentHookParams>,
+    new_hooks: Vec<ComponentHookParams>,
+) -> bool {
+    let is_duplicate = new_hooks.iter().any(|new_hook| {
+        component_hooks
+            .iter()
+            .any(|hook| hook.component == new_hook.component && hook.hook == new_hook.hook)
+    });
+    if is_duplicate {
+        return false;
+    }
+    component_hooks.extend(new_hooks);
+    true
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InitAssetParams {
+    pub asset: String,
+    pub reflect: bool,
+}
+
+impl InitAssetParams {
+    pub fn new(asset: &Path, reflect: bool) -> Self {
+        Self {
+            asset: path_to_string(asset, false),
+            reflect,
+        }
+    }
+}
+
+/// `value` is the loader constructor of `#[auto_register_asset_loader]`, `None` for `#[auto_init_asset_loader]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssetLoaderParams {
+    pub loader: String,
+    pub value: Option<String>,
+}
+
+impl AssetLoaderParams {
+    pub fn init(loader: &Path) -> Self {
+        Self {
+            loader: path_to_string(loader, false),
+            value: None,
+        }
+    }
+
+    pub fn register(loader: &Path, args: &RegisterAssetLoaderArgs) -> Self {
+        Self {
+            loader: path_to_string(loader, false),
+            value: args
+                .value
+                .as_ref()
+                .map(|value| value.to_token_stream().to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StateScopedParams {
+    pub component: String,
+    pub state: String,
+}
+
+impl StateScopedParams {
+    pub fn new(component: &Path, args: &StateScopedArgs) -> Self {
+        Self {
+            component: path_to_string(component, false),
+            state: args.state.to_token_stream().to_string(),
+        }
+    }
 }
 
 pub fn generate_register_types(
@@ -34,6 +560,27 @@ pub fn generate_register_types(
     })
 }
 
+pub fn generate_register_type_data(
+    app_ident: &Ident,
+    items: impl Iterator<Item = TypeDataParams>,
+) -> syn::Result<MacroStream> {
+    let register_type_data = items
+        .map(|item| {
+            let ty = syn::parse_str::<Path>(&item.ty)?;
+            let type_data = syn::parse_str::<Path>(&item.type_data)?;
+            Ok(quote! {
+                #app_ident.register_type_data::<#ty, #type_data>();
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // register_type_data
+            #(#register_type_data)*
+        }
+    })
+}
+
 pub fn generate_add_events(
     app_ident: &Ident,
     items: impl Iterator<Item = String>,
@@ -74,6 +621,119 @@ pub fn generate_init_resources(
     })
 }
 
+pub fn generate_insert_resources(
+    app_ident: &Ident,
+    items: impl Iterator<Item = InsertResourceParams>,
+) -> syn::Result<MacroStream> {
+    let insert_resources = items
+        .map(|item| {
+            let resource = syn::parse_str::<Path>(&item.resource)?;
+            let value = syn::parse_str::<Expr>(&item.value)?;
+            Ok(quote! {
+                #app_ident.insert_resource::<#resource>(#value);
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // insert_resources
+            #(#insert_resources)*
+        }
+    })
+}
+
+pub fn generate_init_gizmo_groups(
+    app_ident: &Ident,
+    items: impl Iterator<Item = String>,
+) -> syn::Result<MacroStream> {
+    let init_gizmo_groups = items
+        .map(|item| {
+            let item = syn::parse_str::<Path>(&item)?;
+            Ok(quote! {
+                <bevy_app::App as bevy_gizmos::AppGizmoBuilder>::init_gizmo_group::<#item>(#app_ident);
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // init_gizmo_groups
+            #(#init_gizmo_groups)*
+        }
+    })
+}
+
+pub fn generate_insert_gizmo_configs(
+    app_ident: &Ident,
+    items: impl Iterator<Item = GizmoConfigParams>,
+) -> syn::Result<MacroStream> {
+    let insert_gizmo_configs = items
+        .map(|item| {
+            let group = syn::parse_str::<Path>(&item.group)?;
+            let config = syn::parse_str::<Expr>(&item.config)?;
+            Ok(quote! {
+                <bevy_app::App as bevy_gizmos::AppGizmoBuilder>::insert_gizmo_config(
+                    #app_ident,
+                    <#group as ::core::default::Default>::default(),
+                    #config,
+                );
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // insert_gizmo_configs
+            #(#insert_gizmo_configs)*
+        }
+    })
+}
+
+pub fn generate_init_non_send_resources(
+    app_ident: &Ident,
+    items: impl Iterator<Item = String>,
+) -> syn::Result<MacroStream> {
+    let init_non_send_resources = items
+        .map(|item| {
+            let item = syn::parse_str::<Path>(&item)?;
+            Ok(quote! {
+                #app_ident.init_non_send_resource::<#item>();
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // init_non_send_resources
+            #(#init_non_send_resources)*
+        }
+    })
+}
+
+pub fn generate_init_resources_from_file(
+    app_ident: &Ident,
+    items: impl Iterator<Item = InitResourceFromFileParams>,
+) -> syn::Result<MacroStream> {
+    let init_resources_from_file = items
+        .map(|item| {
+            let resource = syn::parse_str::<Path>(&item.resource)?;
+            let from_file = LitStr::new(&item.from_file, Span::call_site());
+            let from_str = ResourceFileFormat::from_lit(&from_file)?.from_str_fn();
+            // include_str! makes cargo rebuild when the file changes
+            // the contents are checked against the resource by the test emitted next to it
+            Ok(quote! {
+                #app_ident.insert_resource::<#resource>(
+                    #from_str::<#resource>(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #from_file)))
+                        .expect(concat!(#from_file, " does not deserialize into ", stringify!(#resource))),
+                );
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // init_resources_from_file
+            #(#init_resources_from_file)*
+        }
+    })
+}
+
 pub fn generate_auto_names(
     app_ident: &Ident,
     items: impl Iterator<Item = String>,
@@ -94,3 +754,517 @@ pub fn generate_auto_names(
         }
     })
 }
+
+pub fn generate_add_systems(
+    app_ident: &Ident,
+    items: impl Iterator<Item = AddSystemParams>,
+) -> syn::Result<MacroStream> {
+    struct SystemGroup {
+        schedule: String,
+        schedule_macro: Ident,
+        chain: Option<String>,
+        systems: Vec<MacroStream>,
+    }
+
+    // systems sharing a chain group in the same schedule are added together in declaration order
+    let mut groups: Vec<SystemGroup> = vec![];
+    for item in items {
+        let system = item.system_config()?;
+        let chain_group = item.chain.as_ref().and_then(|chain| {
+            groups.iter_mut().find(|group| {
+                group.schedule == item.schedule && group.chain.as_ref() == Some(chain)
+            })
+        });
+        if let Some(group) = chain_group {
+            group.systems.push(system);
+        } else {
+            groups.push(SystemGroup {
+                schedule_macro: item.config_macro_ident(),
+                schedule: item.schedule,
+                chain: item.chain,
+                systems: vec![system],
+            });
+        }
+    }
+
+    let add_systems = groups
+        .into_iter()
+        .map(|group| {
+            let schedule_macro = group.schedule_macro;
+            let systems = group.systems;
+            let systems = if group.chain.is_some() {
+                quote! { bevy_ecs::schedule::IntoSystemConfigs::chain((#(#systems,)*)) }
+            } else {
+                quote! { #(#systems)* }
+            };
+            quote! {
+                #app_ident.add_systems(#schedule_macro!(schedule), #systems);
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(quote! {
+        {
+            // add_systems
+            #(#add_systems)*
+        }
+    })
+}
+
+pub fn generate_add_schedules(
+    app_ident: &Ident,
+    items: impl Iterator<Item = AddScheduleParams>,
+) -> syn::Result<MacroStream> {
+    let add_schedules = items
+        .map(|item| {
+            let label = syn::parse_str::<Path>(&item.label)?;
+            let anchor = syn::parse_str::<Expr>(&item.anchor)?;
+            let order = if item.fixed {
+                quote! { bevy_app::FixedMainScheduleOrder }
+            } else {
+                quote! { bevy_app::MainScheduleOrder }
+            };
+            let insert = match (item.startup, item.before) {
+                (false, false) => format_ident!("insert_after"),
+                (false, true) => format_ident!("insert_before"),
+                (true, false) => format_ident!("insert_startup_after"),
+                (true, true) => format_ident!("insert_startup_before"),
+            };
+            let executor = item.executor.map(|executor| {
+                let executor = format_ident!("{executor}");
+                quote! {
+                    schedule.set_executor_kind(bevy_ecs::schedule::ExecutorKind::#executor);
+                }
+            });
+            let ambiguity_detection = item.ambiguity_detection.map(|level| {
+                let level = format_ident!("{level}");
+                quote! {
+                    schedule.set_build_settings(bevy_ecs::schedule::ScheduleBuildSettings {
+                        ambiguity_detection: bevy_ecs::schedule::LogLevel::#level,
+                        ..schedule.get_build_settings()
+                    });
+                }
+            });
+            let edit_schedule = (executor.is_some() || ambiguity_detection.is_some()).then(|| {
+                quote! {
+                    #app_ident.edit_schedule(#label, |schedule| {
+                        #executor
+                        #ambiguity_detection
+                    });
+                }
+            });
+            Ok(quote! {
+                #app_ident.init_schedule(#label);
+                #app_ident
+                    .world_mut()
+                    .resource_mut::<#order>()
+                    .#insert(#anchor, #label);
+                #edit_schedule
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // add_schedules
+            #(#add_schedules)*
+        }
+    })
+}
+
+pub fn generate_configure_sets(
+    app_ident: &Ident,
+    items: impl Iterator<Item = ConfigureSetParams>,
+) -> syn::Result<MacroStream> {
+    let configure_sets = items
+        .map(|item| {
+            let set = syn::parse_str::<Path>(&item.set)?;
+            let schedule = syn::parse_str::<Expr>(&item.schedule)?;
+            let variants = item
+                .variants
+                .iter()
+                .map(|(variant, config)| {
+                    let variant = format_ident!("{variant}");
+                    config.config(quote! { #set::#variant })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            let mut configs = quote! { (#(#variants,)*) };
+            if item.chain {
+                configs = quote! { bevy_ecs::schedule::IntoSystemSetConfigs::chain(#configs) };
+            }
+            let configs = item.config.config(configs)?;
+            Ok(quote! {
+                #app_ident.configure_sets(#schedule, #configs);
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // configure_sets
+            #(#configure_sets)*
+        }
+    })
+}
+
+/// Generates the `OneShotSystems` resource holding the `SystemId` of each one-shot system
+pub fn generate_one_shot_systems_resource(
+    items: &[OneShotSystemParams],
+) -> syn::Result<Option<MacroStream>> {
+    if items.is_empty() {
+        return Ok(None);
+    }
+    let fields = items
+        .iter()
+        .map(|item| {
+            let field = item.field_ident()?;
+            let input = syn::parse_str::<Type>(&item.input)?;
+            let output = syn::parse_str::<Type>(&item.output)?;
+            Ok(quote! {
+                pub #field: bevy_ecs::system::SystemId<#input, #output>
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(Some(quote! {
+        #[derive(bevy_ecs::system::Resource, Debug, Copy, Clone)]
+        pub struct OneShotSystems {
+            #(#fields,)*
+        }
+    }))
+}
+
+pub fn generate_register_one_shot_systems(
+    app_ident: &Ident,
+    items: &[OneShotSystemParams],
+) -> syn::Result<MacroStream> {
+    if items.is_empty() {
+        return Ok(quote! {});
+    }
+    let fields = items
+        .iter()
+        .map(|item| {
+            let field = item.field_ident()?;
+            let system = syn::parse_str::<Path>(&item.system)?;
+            Ok(quote! {
+                #field: #app_ident.register_system(#system)
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // register_one_shot_systems
+            let one_shot_systems = OneShotSystems {
+                #(#fields,)*
+            };
+            #app_ident.insert_resource(one_shot_systems);
+        }
+    })
+}
+
+pub fn generate_add_observers(
+    app_ident: &Ident,
+    items: impl Iterator<Item = String>,
+) -> syn::Result<MacroStream> {
+    let add_observers = items
+        .map(|item| {
+            let item = syn::parse_str::<Path>(&item)?;
+            Ok(quote! {
+                #app_ident.add_observer(#item);
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // add_observers
+            #(#add_observers)*
+        }
+    })
+}
+
+pub fn generate_init_states(
+    app_ident: &Ident,
+    items: impl Iterator<Item = InitStateParams>,
+) -> syn::Result<MacroStream> {
+    let init_states = items
+        .map(|item| {
+            let state = syn::parse_str::<Path>(&item.state)?;
+            let init_state = match item.value {
+                Some(value) => {
+                    let value = syn::parse_str::<Expr>(&value)?;
+                    quote! {
+                        <bevy_app::App as bevy_state::app::AppExtStates>::insert_state::<#state>(#app_ident, #value);
+                    }
+                }
+                None => quote! {
+                    <bevy_app::App as bevy_state::app::AppExtStates>::init_state::<#state>(#app_ident);
+                },
+            };
+            let scoped_entities = item.scoped_entities.then(|| {
+                quote! {
+                    <bevy_app::App as bevy_state::app::AppExtStates>::enable_state_scoped_entities::<#state>(#app_ident);
+                }
+            });
+            Ok(quote! {
+                #init_state
+                #scoped_entities
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    if init_states.is_empty() {
+        return Ok(quote! {});
+    }
+    Ok(quote! {
+        {
+            // init_states
+            #(#init_states)*
+        }
+    })
+}
+
+pub fn generate_add_sub_states(
+    app_ident: &Ident,
+    items: impl Iterator<Item = String>,
+) -> syn::Result<MacroStream> {
+    let add_sub_states = items
+        .map(|item| {
+            let item = syn::parse_str::<Path>(&item)?;
+            Ok(quote! {
+                <bevy_app::App as bevy_state::app::AppExtStates>::add_sub_state::<#item>(#app_ident);
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // add_sub_states
+            #(#add_sub_states)*
+        }
+    })
+}
+
+pub fn generate_add_computed_states(
+    app_ident: &Ident,
+    items: impl Iterator<Item = String>,
+) -> syn::Result<MacroStream> {
+    let add_computed_states = items
+        .map(|item| {
+            let item = syn::parse_str::<Path>(&item)?;
+            Ok(quote! {
+                <bevy_app::App as bevy_state::app::AppExtStates>::add_computed_state::<#item>(#app_ident);
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // add_computed_states
+            #(#add_computed_states)*
+        }
+    })
+}
+
+pub fn generate_state_scoped(
+    app_ident: &Ident,
+    items: impl Iterator<Item = StateScopedParams>,
+) -> syn::Result<MacroStream> {
+    let state_scoped = items
+        .map(|item| {
+            let component = syn::parse_str::<Path>(&item.component)?;
+            let state = syn::parse_str::<Expr>(&item.state)?;
+            Ok(quote! {
+                #app_ident.register_required_components_with::<#component, bevy_state::state_scoped::StateScoped<_>>(|| bevy_state::state_scoped::StateScoped(#state));
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // state_scoped
+            #(#state_scoped)*
+        }
+    })
+}
+
+pub fn generate_required_components(
+    app_ident: &Ident,
+    items: impl Iterator<Item = RequiredComponentParams>,
+) -> syn::Result<MacroStream> {
+    let required_components = items
+        .map(|item| {
+            let component = syn::parse_str::<Path>(&item.component)?;
+            let required = syn::parse_str::<Type>(&item.required)?;
+            Ok(match item.constructor {
+                Some(constructor) => {
+                    let constructor = syn::parse_str::<Expr>(&constructor)?;
+                    quote! {
+                        #app_ident.register_required_components_with::<#component, #required>(|| #constructor);
+                    }
+                }
+                None => quote! {
+                    #app_ident.register_required_components::<#component, #required>();
+                },
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // required_components
+            #(#required_components)*
+        }
+    })
+}
+
+pub fn generate_component_hooks(
+    app_ident: &Ident,
+    items: impl Iterator<Item = ComponentHookParams>,
+) -> syn::Result<MacroStream> {
+    let component_hooks = items
+        .map(|item| {
+            let component = syn::parse_str::<Path>(&item.component)?;
+            let hook = format_ident!("{}", item.hook);
+            let function = syn::parse_str::<Expr>(&item.function)?;
+            Ok(quote! {
+                #app_ident.world_mut().register_component_hooks::<#component>().#hook(#function);
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // component_hooks
+            #(#component_hooks)*
+        }
+    })
+}
+
+pub fn generate_init_assets(
+    app_ident: &Ident,
+    items: impl Iterator<Item = InitAssetParams>,
+) -> syn::Result<MacroStream> {
+    let init_assets = items
+        .map(|item| {
+            let asset = syn::parse_str::<Path>(&item.asset)?;
+            let reflect = item.reflect.then(|| {
+                quote! {
+                    <bevy_app::App as bevy_asset::AssetApp>::register_asset_reflect::<#asset>(#app_ident);
+                    #app_ident.register_type::<bevy_asset::Handle<#asset>>();
+                }
+            });
+            Ok(quote! {
+                <bevy_app::App as bevy_asset::AssetApp>::init_asset::<#asset>(#app_ident);
+                #reflect
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // init_assets
+            #(#init_assets)*
+        }
+    })
+}
+
+pub fn generate_asset_loaders(
+    app_ident: &Ident,
+    items: impl Iterator<Item = AssetLoaderParams>,
+) -> syn::Result<MacroStream> {
+    let asset_loaders = items
+        .map(|item| {
+            let loader = syn::parse_str::<Path>(&item.loader)?;
+            Ok(match item.value {
+                Some(value) => {
+                    let value = syn::parse_str::<Expr>(&value)?;
+                    quote! {
+                        <bevy_app::App as bevy_asset::AssetApp>::register_asset_loader::<#loader>(#app_ident, #value);
+                    }
+                }
+                None => quote! {
+                    <bevy_app::App as bevy_asset::AssetApp>::init_asset_loader::<#loader>(#app_ident);
+                },
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // asset_loaders
+            // must come after init_assets so the loaded asset types are initialized
+            #(#asset_loaders)*
+        }
+    })
+}
+
+pub fn generate_asset_collections(
+    app_ident: &Ident,
+    items: impl Iterator<Item = String>,
+    system_set: Option<&Ident>,
+) -> syn::Result<MacroStream> {
+    let asset_collections = items
+        .map(|item| {
+            let item = syn::parse_str::<Path>(&item)?;
+            let load_system = system_configs_call(
+                quote! {
+                    <#item as ::bevy_auto_plugin::asset_collection::AssetCollection>::load_system()
+                },
+                "in_set",
+                system_set,
+            );
+            Ok(quote! {
+                #app_ident.add_systems(bevy_app::Startup, #load_system);
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // asset_collections
+            #(#asset_collections)*
+        }
+    })
+}
+
+/// Generates the `PluginSet` of `#[auto_plugin(system_set)]`, returning its ident and declaration
+pub fn generate_plugin_system_set() -> (Ident, MacroStream) {
+    let ident = format_ident!("PluginSet");
+    let declaration = quote! {
+        /// Contains every system added by the plugin
+        #[derive(bevy_ecs::schedule::SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct #ident;
+    };
+    (ident, declaration)
+}
+
+/// Adds every system to the plugin `SystemSet`
+pub fn add_systems_in_set(items: &mut [AddSystemParams], system_set: &Ident) {
+    for item in items {
+        item.plugin_set = Some(system_set.to_string());
+    }
+}
+
+pub fn generate_register_diagnostics(
+    app_ident: &Ident,
+    items: impl Iterator<Item = DiagnosticParams>,
+) -> syn::Result<MacroStream> {
+    let register_diagnostics = items
+        .map(|item| {
+            let path = syn::parse_str::<Path>(&item.path)?;
+            let max_history = item
+                .max_history
+                .map(|max_history| {
+                    let max_history = syn::parse_str::<Expr>(&max_history)?;
+                    Ok::<_, syn::Error>(quote! { .with_max_history_length(#max_history) })
+                })
+                .transpose()?;
+            let suffix = item
+                .suffix
+                .map(|suffix| {
+                    let suffix = syn::parse_str::<Expr>(&suffix)?;
+                    Ok::<_, syn::Error>(quote! { .with_suffix(#suffix) })
+                })
+                .transpose()?;
+            Ok(quote! {
+                <bevy_app::App as bevy_diagnostic::RegisterDiagnostic>::register_diagnostic(
+                    #app_ident,
+                    bevy_diagnostic::Diagnostic::new(#path) #max_history #suffix,
+                );
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
+        {
+            // register_diagnostics
+            #(#register_diagnostics)*
+        }
+    })
+}
@@ -0,0 +1,164 @@
+use crate::attribute_args::InitResourceFromFileArgs;
+use crate::util::resolve_path_from_item;
+use proc_macro2::TokenStream as MacroStream;
+use quote::{format_ident, quote};
+use syn::{Error, Item, LitStr};
+
+/// Formats supported by `#[auto_init_resource(from_file = "...")]`, each behind the cargo feature of the same name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceFileFormat {
+    Toml,
+    Ron,
+}
+
+impl ResourceFileFormat {
+    /// Resolves the format from the file extension, erroring when the format's feature is disabled
+    pub fn from_lit(from_file: &LitStr) -> syn::Result<Self> {
+        let path = from_file.value();
+        let format = match path.rsplit_once('.').map(|(_, extension)| extension) {
+            Some("toml") => Self::Toml,
+            Some("ron") => Self::Ron,
+            _ => {
+                return Err(Error::new(
+                    from_file.span(),
+                    "unsupported file format, expected a .toml or .ron file",
+                ))
+            }
+        };
+        if !format.enabled() {
+            return Err(Error::new(
+                from_file.span(),
+                format!(
+                    "loading .{0} files requires the `{0}` feature of bevy_auto_plugin",
+                    format.feature()
+                ),
+            ));
+        }
+        Ok(format)
+    }
+
+    fn feature(self) -> &'static str {
+        match self {
+            Self::Toml => "toml",
+            Self::Ron => "ron",
+        }
+    }
+
+    fn enabled(self) -> bool {
+        match self {
+            Self::Toml => cfg!(feature = "toml"),
+            Self::Ron => cfg!(feature = "ron"),
+        }
+    }
+
+    /// `from_str` of the format, re-exported by `bevy_auto_plugin` so users don't need the dependency
+    pub fn from_str_fn(self) -> MacroStream {
+        match self {
+            Self::Toml => quote! { ::bevy_auto_plugin::__private::toml::from_str },
+            Self::Ron => quote! { ::bevy_auto_plugin::__private::ron::from_str },
+        }
+    }
+
+    /// Parses the contents without a target type, returning `line:column: message` on syntax errors
+    #[cfg_attr(not(all(feature = "toml", feature = "ron")), allow(unused_variables))]
+    fn check_syntax(self, contents: &str) -> Result<(), String> {
+        match self {
+            #[cfg(feature = "toml")]
+            Self::Toml => contents.parse::<toml::Table>().map(drop).map_err(|err| {
+                let (line, column) = err
+                    .span()
+                    .map(|span| line_column(contents, span.start))
+                    .unwrap_or((1, 1));
+                format!("{line}:{column}: {}", err.message())
+            }),
+            #[cfg(feature = "ron")]
+            Self::Ron => ron::from_str::<ron::Value>(contents)
+                .map(drop)
+                .map_err(|err| format!("{}:{}: {}", err.position.line, err.position.col, err.code)),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("disabled formats are rejected by from_lit"),
+        }
+    }
+}
+
+/// 1-based line and column of a byte offset
+#[cfg(feature = "toml")]
+fn line_column(contents: &str, offset: usize) -> (usize, usize) {
+    let before = &contents[..offset.min(contents.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+    (line, column)
+}
+
+/// Reads the file relative to `CARGO_MANIFEST_DIR` and checks its syntax, errors point at the attribute argument
+pub fn check_resource_file(from_file: &LitStr) -> syn::Result<ResourceFileFormat> {
+    let format = ResourceFileFormat::from_lit(from_file)?;
+    let relative_path = from_file.value();
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|err| Error::new(from_file.span(), format!("CARGO_MANIFEST_DIR: {err}")))?;
+    let path = std::path::Path::new(&manifest_dir).join(&relative_path);
+    let contents = std::fs::read_to_string(&path).map_err(|err| {
+        Error::new(
+            from_file.span(),
+            format!("failed to read {}: {err}", path.display()),
+        )
+    })?;
+    format
+        .check_syntax(&contents)
+        .map_err(|err| Error::new(from_file.span(), format!("{relative_path}:{err}")))?;
+    Ok(format)
+}
+
+/// Emitted next to the resource: a compile time check that the type implements `Deserialize`, and a test
+/// deserializing the file with the type's own `Deserialize` impl so content mismatches fail `cargo test`
+pub fn resource_file_checks(
+    item: &Item,
+    args: &InitResourceFromFileArgs,
+) -> syn::Result<MacroStream> {
+    let resource = resolve_path_from_item(item)?;
+    let from_file = args
+        .from_file
+        .as_ref()
+        .unwrap_or_else(|| unreachable!("from_file is validated when parsing"));
+    let from_str = ResourceFileFormat::from_lit(from_file)?.from_str_fn();
+    let ident = &resource
+        .segments
+        .last()
+        .unwrap_or_else(|| unreachable!("resolved from the item ident"))
+        .ident;
+    let test_ident = format_ident!("auto_init_resource_{}_from_file", to_snake_case(ident));
+    let contents = quote! {
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #from_file))
+    };
+    Ok(quote! {
+        const _: fn() = || {
+            let _: #resource = #from_str(#contents).unwrap();
+        };
+
+        #[cfg(test)]
+        #[test]
+        fn #test_ident() {
+            if let Err(err) = #from_str::<#resource>(#contents) {
+                panic!("{} does not deserialize into {}: {err}", #from_file, stringify!(#resource));
+            }
+        }
+    })
+}
+
+fn to_snake_case(ident: &syn::Ident) -> String {
+    let mut snake = String::new();
+    let mut previous_lowercase = false;
+    for c in ident.to_string().chars() {
+        if c.is_uppercase() {
+            if previous_lowercase {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+            previous_lowercase = false;
+        } else {
+            snake.push(c);
+            previous_lowercase = c.is_lowercase() || c.is_ascii_digit();
+        }
+    }
+    snake
+}
@@ -1,11 +1,19 @@
-use proc_macro2::Ident;
-use quote::quote;
+use crate::attribute_args::{
+    AddScheduleArgs, AddSystemArgs, AssetFieldArgs, AttributeArgs, ComponentHooksArgs,
+    GizmoConfigArgs, InitResourceFromFileArgs, InitStateArgs, InsertResourceArgs,
+    RegisterAssetLoaderArgs, RegisterDiagnosticArgs, RegisterTypeDataArgs, RequireArgs,
+    StateScopedArgs,
+};
+use crate::ConfigureSetParams;
+use proc_macro2::{Ident, TokenStream as MacroStream};
+use quote::{format_ident, quote};
+use syn::parse::ParseStream;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
 use syn::{
-    Attribute, Error, FnArg, Generics, Item, ItemFn, ItemMod, Pat, Path, PathArguments,
-    PathSegment, Token, Type, TypeReference,
+    parse_quote, Attribute, Error, Fields, FnArg, Generics, Item, ItemEnum, ItemFn, ItemMod,
+    ItemStruct, Pat, Path, PathArguments, PathSegment, ReturnType, Token, Type, TypeReference,
 };
 
 pub fn resolve_path_from_item_or_args(
@@ -42,6 +50,155 @@ pub fn resolve_path_from_item_or_args(
     }
 }
 
+/// For attributes where the arguments are not a path, e.g. `#[attribute(key = value)]`
+pub fn resolve_path_from_item(item: &Item) -> syn::Result<Path> {
+    let struct_or_enum = StructOrEnumRef::try_from(item)?;
+    let path = ident_to_path(struct_or_enum.ident);
+    validate_generic_counts(struct_or_enum.generics, &path)?;
+    Ok(path)
+}
+
+/// Same as [`resolve_path_from_item`] with the generics passed as an argument, e.g. `#[attribute(key = value, generics(bool))]`
+pub fn resolve_path_from_item_with_generics(
+    item: &Item,
+    generics: Option<&Punctuated<Type, Comma>>,
+) -> syn::Result<Path> {
+    let Some(generics) = generics else {
+        return resolve_path_from_item(item);
+    };
+    let struct_or_enum = StructOrEnumRef::try_from(item)?;
+    let ident = struct_or_enum.ident;
+    let path: Path = parse_quote!(#ident<#generics>);
+    if struct_or_enum.generics.type_params().count() == 0 {
+        return Err(Error::new(
+            generics.span(),
+            format!("{ident} has no generic parameters"),
+        ));
+    }
+    validate_generic_counts(struct_or_enum.generics, &path)?;
+    Ok(path)
+}
+
+/// Strips the `#[configure_set(...)]` variant attributes once no other `#[auto_configure_set]` attribute needs them
+pub fn strip_configure_set_attributes(item: &mut ItemEnum) {
+    if item
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("auto_configure_set"))
+    {
+        return;
+    }
+    for variant in item.variants.iter_mut() {
+        variant
+            .attrs
+            .retain(|attr| !attr.path().is_ident("configure_set"));
+    }
+}
+
+/// Strips the `#[asset(path = "...")]` field attributes and implements `AssetCollection` for the struct
+pub fn expand_asset_collection(item: &mut ItemStruct) -> syn::Result<MacroStream> {
+    if !item.generics.params.is_empty() {
+        return Err(Error::new(
+            item.generics.span(),
+            "generic asset collections are not supported",
+        ));
+    }
+    let Fields::Named(fields) = &mut item.fields else {
+        return Err(Error::new(
+            item.ident.span(),
+            "expected a struct with named fields",
+        ));
+    };
+    let mut asset_fields = vec![];
+    let mut field_values = vec![];
+    for field in fields.named.iter_mut() {
+        let ident = field.ident.clone().expect("named field");
+        let mut asset_path = None;
+        for attr in std::mem::take(&mut field.attrs) {
+            if !attr.path().is_ident("asset") {
+                field.attrs.push(attr);
+                continue;
+            }
+            if asset_path.is_some() {
+                return Err(Error::new(attr.span(), "asset path already specified"));
+            }
+            asset_path = AssetFieldArgs::from_attribute(&attr)?.path;
+        }
+        match asset_path {
+            Some(asset_path) => {
+                field_values.push(
+                    quote! { #ident: bevy_asset::AssetServer::load(&asset_server, #asset_path) },
+                );
+                asset_fields.push(ident);
+            }
+            None => field_values.push(quote! { #ident: ::core::default::Default::default() }),
+        }
+    }
+    if asset_fields.is_empty() {
+        return Err(Error::new(
+            item.ident.span(),
+            "expected at least one field with #[asset(path = \"...\")]",
+        ));
+    }
+    let ident = &item.ident;
+    Ok(quote! {
+        impl ::bevy_auto_plugin::asset_collection::AssetCollection for #ident {
+            type LoadSystem =
+                fn(bevy_ecs::system::Commands, bevy_ecs::system::Res<bevy_asset::AssetServer>);
+            type LoadedCondition = fn(
+                ::core::option::Option<bevy_ecs::system::Res<Self>>,
+                bevy_ecs::system::Res<bevy_asset::AssetServer>,
+            ) -> bool;
+
+            fn load_system() -> Self::LoadSystem {
+                |mut commands, asset_server| {
+                    commands.insert_resource(Self {
+                        #(#field_values,)*
+                    });
+                }
+            }
+
+            fn loaded_condition() -> Self::LoadedCondition {
+                |collection, asset_server| {
+                    collection.is_some_and(|collection| {
+                        #(asset_server.is_loaded_with_dependencies(
+                            bevy_asset::Handle::id(&collection.#asset_fields),
+                        ))&&*
+                    })
+                }
+            }
+        }
+    })
+}
+
+/// Splits `#[auto_register_type_data(...)]` arguments into the registered type and its type data
+pub fn resolve_type_data_args(
+    item: &Item,
+    args: RegisterTypeDataArgs,
+) -> syn::Result<(Path, Vec<Path>)> {
+    let struct_or_enum = StructOrEnumRef::try_from(item)?;
+    let mut paths = args.paths.into_iter().peekable();
+    let is_instantiation = paths.peek().is_some_and(|path| {
+        path.segments
+            .first()
+            .is_some_and(|segment| segment.ident == *struct_or_enum.ident)
+    });
+    let path = if is_instantiation {
+        let instantiation = paths.next().into_iter().collect();
+        resolve_path_from_item_or_args(item, Some(instantiation))?
+    } else {
+        resolve_path_from_item(item)?
+    };
+    let type_data = paths.collect::<Vec<_>>();
+    if type_data.is_empty() {
+        return Err(Error::new(
+            item.span(),
+            "expected at least one type data. Example: #[auto_register_type_data(ReflectDefault)]",
+        ));
+    }
+    Ok((path, type_data))
+}
+
 pub fn path_to_string(path: &Path, strip_spaces: bool) -> String {
     let path_string = quote!(#path).to_string();
     if strip_spaces {
@@ -55,7 +212,32 @@ pub enum Target {
     RegisterTypes,
     AddEvents,
     InitResources,
+    InitNonSendResources,
+    InsertResources(Box<InsertResourceArgs>),
+    InitResourcesFromFile(Box<InitResourceFromFileArgs>),
     RequiredComponentAutoName,
+    AddSystems(Box<AddSystemArgs>),
+    ConfigureSets(Box<ConfigureSetParams>),
+    AddSchedules(Box<AddScheduleArgs>),
+    InitGizmoGroups,
+    RegisterDiagnostics(Box<RegisterDiagnosticArgs>),
+    InsertGizmoConfigs(Box<GizmoConfigArgs>),
+    RegisterOneShotSystems(Box<SystemIo>),
+    AddObservers,
+    InitStates(Box<InitStateArgs>),
+    AddSubStates,
+    AddComputedStates,
+    StateScoped(Box<StateScopedArgs>),
+    RequiredComponents(Box<RequireArgs>),
+    ComponentHooks(Box<ComponentHooksArgs>),
+    RegisterTypeData(Vec<Path>),
+    /// `reflect` is set when the asset derives `Reflect`
+    InitAssets {
+        reflect: bool,
+    },
+    InitAssetLoaders,
+    AssetCollections,
+    RegisterAssetLoaders(Box<RegisterAssetLoaderArgs>),
 }
 
 pub struct StructOrEnumRef<'a> {
@@ -92,6 +274,173 @@ impl<'a> TryFrom<&'a Item> for StructOrEnumRef<'a> {
     }
 }
 
+/// Asserts the type in the `#[source(SourceState = SourceState::Variant)]` attribute of a `SubStates` item is a `States` type
+pub fn sub_state_source_check(item: &Item) -> syn::Result<MacroStream> {
+    let struct_or_enum = StructOrEnumRef::try_from(item)?;
+    let Some(source_attr) = struct_or_enum
+        .attributes
+        .iter()
+        .find(|attr| attr.path().is_ident("source"))
+    else {
+        // missing #[source(...)] is reported by the SubStates derive
+        return Ok(quote! {});
+    };
+    let source = source_attr.parse_args_with(|input: ParseStream| {
+        let source = input.parse::<Type>()?;
+        input.parse::<Token![=]>()?;
+        input.parse::<MacroStream>()?;
+        Ok(source)
+    })?;
+    Ok(quote! {
+        const _: () = {
+            #[allow(dead_code)]
+            fn auto_add_sub_state_source_check() {
+                fn assert_source_is_state<S: bevy_state::state::States>() {}
+                assert_source_is_state::<#source>();
+            }
+        };
+    })
+}
+
+/// `impl Trait for Type` item
+pub struct ImplTraitRef<'a> {
+    pub self_ty: &'a Type,
+    pub trait_path: &'a Path,
+    pub generics: &'a Generics,
+    pub attributes: &'a Vec<Attribute>,
+}
+
+impl<'a> TryFrom<&'a Item> for ImplTraitRef<'a> {
+    type Error = Error;
+
+    fn try_from(item: &'a Item) -> std::result::Result<Self, Self::Error> {
+        let Item::Impl(ref impl_item) = item else {
+            return Err(Error::new(item.span(), "expected impl"));
+        };
+        let Some((_, ref trait_path, _)) = impl_item.trait_ else {
+            return Err(Error::new(item.span(), "expected trait impl"));
+        };
+        Ok(Self {
+            self_ty: &impl_item.self_ty,
+            trait_path,
+            generics: &impl_item.generics,
+            attributes: &impl_item.attrs,
+        })
+    }
+}
+
+/// Resolves the implementing type of an `impl Trait for Type` item
+pub fn resolve_path_from_impl_item(item: &Item) -> syn::Result<Path> {
+    let impl_trait = ImplTraitRef::try_from(item)?;
+    if !impl_trait.generics.params.is_empty() {
+        return Err(Error::new(
+            impl_trait.generics.span(),
+            "generic impls are not supported, implement the trait for a concrete type",
+        ));
+    }
+    let Type::Path(self_ty) = impl_trait.self_ty else {
+        return Err(Error::new(
+            impl_trait.self_ty.span(),
+            "expected a type path",
+        ));
+    };
+    Ok(self_ty.path.clone())
+}
+
+/// Resolves the `ReflectTrait` type data generated by `#[reflect_trait]` for an `impl Trait for Type` item
+pub fn resolve_reflect_trait_type_data(item: &Item) -> syn::Result<Path> {
+    let impl_trait = ImplTraitRef::try_from(item)?;
+    let mut type_data = impl_trait.trait_path.clone();
+    let Some(last_segment) = type_data.segments.last_mut() else {
+        return Err(Error::new(
+            impl_trait.trait_path.span(),
+            "expected a trait path",
+        ));
+    };
+    *last_segment = PathSegment::from(format_ident!("Reflect{}", last_segment.ident));
+    Ok(type_data)
+}
+
+/// Checks if `#[derive(...)]` on the item includes `derive_name`, e.g. `Reflect` or `bevy::prelude::Reflect`
+pub fn item_derives(item: &Item, derive_name: &str) -> syn::Result<bool> {
+    let struct_or_enum = StructOrEnumRef::try_from(item)?;
+    for attr in struct_or_enum.attributes {
+        if !attr.path().is_ident("derive") {
+            continue;
+        }
+        let derives = attr.parse_args_with(Punctuated::<Path, Comma>::parse_terminated)?;
+        if derives.iter().any(|derive| {
+            derive
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == derive_name)
+        }) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+pub struct FnRef<'a> {
+    pub ident: &'a Ident,
+    pub generics: &'a Generics,
+    pub attributes: &'a Vec<Attribute>,
+}
+
+impl<'a> TryFrom<&'a Item> for FnRef<'a> {
+    type Error = Error;
+
+    fn try_from(item: &'a Item) -> std::result::Result<Self, Self::Error> {
+        let Item::Fn(ref fn_item) = item else {
+            return Err(Error::new(item.span(), "expected fn"));
+        };
+        Ok(Self {
+            ident: &fn_item.sig.ident,
+            generics: &fn_item.sig.generics,
+            attributes: &fn_item.attrs,
+        })
+    }
+}
+
+pub fn resolve_path_from_fn_item(item: &Item) -> syn::Result<Path> {
+    let fn_ref = FnRef::try_from(item)?;
+    let path = ident_to_path(fn_ref.ident);
+    validate_generic_counts(fn_ref.generics, &path)?;
+    Ok(path)
+}
+
+/// Input and output types of a system fn, used to name its `SystemId<I, O>`
+pub struct SystemIo {
+    pub input: Type,
+    pub output: Type,
+}
+
+impl SystemIo {
+    pub fn from_item(item: &Item) -> syn::Result<Self> {
+        let Item::Fn(ref fn_item) = item else {
+            return Err(Error::new(item.span(), "expected fn"));
+        };
+        let is_system_input = |ty: &Type| -> bool {
+            let Type::Path(type_path) = ty else {
+                return false;
+            };
+            type_path.path.segments.last().is_some_and(|segment| {
+                matches!(segment.ident.to_string().as_str(), "In" | "InRef" | "InMut")
+            })
+        };
+        // only the first param can be the system input
+        let input = match fn_item.sig.inputs.first() {
+            Some(FnArg::Typed(pat_type)) if is_system_input(&pat_type.ty) => (*pat_type.ty).clone(),
+            _ => parse_quote!(()),
+        };
+        let output = match &fn_item.sig.output {
+            ReturnType::Default => parse_quote!(()),
+            ReturnType::Type(_, ty) => (**ty).clone(),
+        };
+        Ok(Self { input, output })
+    }
+}
+
 pub struct FnParamMutabilityCheckErrMessages {
     pub not_mutable_message: String,
     pub not_found_message: String,
@@ -218,7 +567,15 @@ pub fn items_with_attribute_macro(
     items: &Vec<syn::Item>,
     attribute_name: &'static str,
 ) -> syn::Result<Vec<ItemWithAttributeMatch>> {
-    let is_marker = |attr: &&Attribute| -> bool { attr.path().is_ident(attribute_name) };
+    items_with_attribute_macro_matching(items, |attr| attr.path().is_ident(attribute_name))
+}
+
+/// Same as [`items_with_attribute_macro`] but with a custom attribute filter
+pub fn items_with_attribute_macro_matching(
+    items: &Vec<syn::Item>,
+    is_marker: impl Fn(&Attribute) -> bool,
+) -> syn::Result<Vec<ItemWithAttributeMatch>> {
+    let is_marker = |attr: &&Attribute| -> bool { is_marker(attr) };
 
     fn parse(ident: &Ident, attr: &Attribute) -> syn::Result<syn::Path> {
         let mut has_args = false;
@@ -289,3 +646,134 @@ pub fn items_with_attribute_macro(
     }
     Ok(matched_items)
 }
+
+/// Matches `impl Trait for Type` items, the matched path is the implementing type
+pub fn impl_trait_items_with_attribute_macro(
+    items: &Vec<syn::Item>,
+    attribute_name: &'static str,
+) -> syn::Result<Vec<ItemWithAttributeMatch>> {
+    let is_marker = |attr: &&Attribute| -> bool { attr.path().is_ident(attribute_name) };
+
+    let mut matched_items = vec![];
+    for item in items {
+        let Ok(matched_item) = ImplTraitRef::try_from(item) else {
+            continue;
+        };
+        for attr in matched_item.attributes.iter().filter(is_marker) {
+            let path = resolve_path_from_impl_item(item)?;
+            matched_items.push(ItemWithAttributeMatch {
+                item: item.clone(),
+                path,
+                attributes: attr.clone(),
+            });
+        }
+    }
+    Ok(matched_items)
+}
+
+/// Matches `const` items, the matched path is the const ident and the attribute arguments are left to the caller
+pub fn const_items_with_attribute_macro(
+    items: &Vec<syn::Item>,
+    attribute_name: &'static str,
+) -> syn::Result<Vec<ItemWithAttributeMatch>> {
+    let is_marker = |attr: &&Attribute| -> bool { attr.path().is_ident(attribute_name) };
+
+    let mut matched_items = vec![];
+    for item in items {
+        let Item::Const(const_item) = item else {
+            continue;
+        };
+        for attr in const_item.attrs.iter().filter(is_marker) {
+            matched_items.push(ItemWithAttributeMatch {
+                item: item.clone(),
+                path: ident_to_path(&const_item.ident),
+                attributes: attr.clone(),
+            });
+        }
+    }
+    Ok(matched_items)
+}
+
+/// Matches in declaration order, `attribute_names` can be used to collect related attributes together
+pub fn fn_items_with_attribute_macro(
+    items: &Vec<syn::Item>,
+    attribute_names: &[&'static str],
+) -> syn::Result<Vec<ItemWithAttributeMatch>> {
+    let is_marker = |attr: &&Attribute| -> bool {
+        attribute_names
+            .iter()
+            .any(|attribute_name| attr.path().is_ident(attribute_name))
+    };
+
+    let mut matched_items = vec![];
+    for item in items {
+        let Ok(matched_item) = FnRef::try_from(item) else {
+            continue;
+        };
+        for attr in matched_item.attributes.iter().filter(is_marker) {
+            let path = resolve_path_from_fn_item(item)?;
+            matched_items.push(ItemWithAttributeMatch {
+                item: item.clone(),
+                path,
+                attributes: attr.clone(),
+            });
+        }
+    }
+    Ok(matched_items)
+}
+
+/// Like [`items_with_attribute_macro`] but leaves parsing the attribute arguments to the caller, see [`resolve_path_from_item`]
+pub fn struct_or_enum_items_with_attribute_args_macro(
+    items: &Vec<syn::Item>,
+    attribute_name: &'static str,
+) -> syn::Result<Vec<ItemWithAttributeMatch>> {
+    struct_or_enum_items_with_attribute_args_macro_matching(items, |attr| {
+        attr.path().is_ident(attribute_name)
+    })
+}
+
+/// Same as [`struct_or_enum_items_with_attribute_args_macro_matching`] but parses the arguments,
+/// the path is resolved by [`AttributeArgs::resolve_path`]
+pub fn struct_or_enum_items_with_attribute_args_matching<A: AttributeArgs>(
+    items: &Vec<syn::Item>,
+    is_marker: impl Fn(&Attribute) -> bool,
+) -> syn::Result<Vec<(Path, A)>> {
+    let is_marker = |attr: &&Attribute| -> bool { is_marker(attr) };
+
+    let mut matched_items = vec![];
+    for item in items {
+        let Ok(matched_item) = StructOrEnumRef::try_from(item) else {
+            continue;
+        };
+        for attr in matched_item.attributes.iter().filter(is_marker) {
+            let args = A::from_attribute(attr)?;
+            let path = args.resolve_path(item)?;
+            matched_items.push((path, args));
+        }
+    }
+    Ok(matched_items)
+}
+
+/// Same as [`struct_or_enum_items_with_attribute_args_macro`] but with a custom attribute filter
+pub fn struct_or_enum_items_with_attribute_args_macro_matching(
+    items: &Vec<syn::Item>,
+    is_marker: impl Fn(&Attribute) -> bool,
+) -> syn::Result<Vec<ItemWithAttributeMatch>> {
+    let is_marker = |attr: &&Attribute| -> bool { is_marker(attr) };
+
+    let mut matched_items = vec![];
+    for item in items {
+        let Ok(matched_item) = StructOrEnumRef::try_from(item) else {
+            continue;
+        };
+        for attr in matched_item.attributes.iter().filter(is_marker) {
+            let path = resolve_path_from_item(item)?;
+            matched_items.push(ItemWithAttributeMatch {
+                item: item.clone(),
+                path,
+                attributes: attr.clone(),
+            });
+        }
+    }
+    Ok(matched_items)
+}
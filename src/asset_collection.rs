@@ -0,0 +1,55 @@
+//! Runtime support for `#[auto_asset_collection]`.
+
+/// Implemented by `#[auto_asset_collection]` for resources holding asset handles.
+///
+/// The system types are left to the generated impl so this crate doesn't depend on `bevy_asset`.
+pub trait AssetCollection: Sized + 'static {
+    /// Startup system inserting the collection resource, loading every `#[asset(path = "...")]` field
+    type LoadSystem;
+    /// Run condition returning `true` once the collection is inserted and all its assets are loaded
+    type LoadedCondition;
+
+    fn load_system() -> Self::LoadSystem;
+
+    fn loaded_condition() -> Self::LoadedCondition;
+}
+
+/// Run condition that becomes `true` once every asset of the collection `C` is loaded, including dependencies.
+///
+/// # Example
+/// ```no_run
+/// use bevy_app::prelude::*;
+/// use bevy_asset::{Asset, Handle};
+/// use bevy_auto_plugin::asset_collection::asset_collection_loaded;
+/// use bevy_auto_plugin::auto_plugin_module::*;
+/// use bevy_ecs::prelude::*;
+/// use bevy_reflect::TypePath;
+///
+/// #[auto_plugin(init_name=init)]
+/// mod plugin_module {
+///     use super::*;
+///
+///     #[auto_init_asset]
+///     #[derive(Asset, TypePath)]
+///     pub struct Sprite;
+///
+///     #[auto_asset_collection]
+///     #[derive(Resource)]
+///     pub struct PlayerAssets {
+///         #[asset(path = "textures/player.sprite")]
+///         pub sprite: Handle<Sprite>,
+///     }
+/// }
+/// use plugin_module::*;
+///
+/// fn spawn_player(_assets: Res<PlayerAssets>) {}
+///
+/// fn plugin(app: &mut App) {
+///     plugin_module::init(app);
+///     app.add_systems(Update, spawn_player.run_if(asset_collection_loaded::<PlayerAssets>()));
+/// }
+/// # fn main() {}
+/// ```
+pub fn asset_collection_loaded<C: AssetCollection>() -> C::LoadedCondition {
+    C::loaded_condition()
+}
@@ -3,3 +3,14 @@
 pub use bevy_auto_plugin_nightly_proc_macros as auto_plugin;
 #[doc(inline)]
 pub use bevy_auto_plugin_proc_macros as auto_plugin_module;
+
+pub mod asset_collection;
+
+/// Used by generated code, not public API
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "ron")]
+    pub use ron;
+    #[cfg(feature = "toml")]
+    pub use toml;
+}
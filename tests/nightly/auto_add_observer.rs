@@ -0,0 +1,36 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_ecs::prelude::*;
+
+#[auto_init_resource]
+#[derive(Resource, Default)]
+pub struct Counter(pub usize);
+
+#[derive(Event)]
+pub struct Test;
+
+#[auto_add_observer]
+fn on_test(_trigger: Trigger<Test>, mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+}
+
+#[auto_plugin(app=app)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_add_observer() {
+    let mut app = app();
+    app.update();
+    app.world_mut().trigger(Test);
+    assert_eq!(
+        app.world().resource::<Counter>().0,
+        1,
+        "did not auto add observer"
+    );
+}
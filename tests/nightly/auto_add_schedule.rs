@@ -0,0 +1,69 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::{ExecutorKind, LogLevel, ScheduleLabel};
+
+#[auto_init_resource]
+#[derive(Resource, Default)]
+pub struct Order(pub Vec<&'static str>);
+
+#[auto_add_schedule(after = Update, executor = SingleThreaded, ambiguity_detection = Warn)]
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Simulation;
+
+#[auto_add_schedule(after = Startup, startup)]
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Setup;
+
+#[auto_add_system(schedule = Simulation)]
+fn simulation(mut order: ResMut<Order>) {
+    order.0.push("simulation");
+}
+
+#[auto_add_system(schedule = Setup)]
+fn setup(mut order: ResMut<Order>) {
+    order.0.push("setup");
+}
+
+#[auto_add_system(schedule = Startup)]
+fn startup(mut order: ResMut<Order>) {
+    order.0.push("startup");
+}
+
+#[auto_add_system(schedule = Update)]
+fn update(mut order: ResMut<Order>) {
+    order.0.push("update");
+}
+
+#[auto_add_system(schedule = PostUpdate)]
+fn post_update(mut order: ResMut<Order>) {
+    order.0.push("post_update");
+}
+
+#[auto_plugin(app=app)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_add_schedule() {
+    let mut app = app();
+    app.update();
+    assert_eq!(
+        app.world().resource::<Order>().0,
+        vec!["startup", "setup", "update", "simulation", "post_update"],
+        "schedules not inserted in order"
+    );
+    let schedule = app
+        .get_schedule(Simulation)
+        .expect("schedule not initialized");
+    assert_eq!(schedule.get_executor_kind(), ExecutorKind::SingleThreaded);
+    assert_eq!(
+        schedule.get_build_settings().ambiguity_detection,
+        LogLevel::Warn
+    );
+}
@@ -0,0 +1,89 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_state::app::StatesPlugin;
+use bevy_state::prelude::*;
+
+#[auto_init_state]
+#[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub enum GameState {
+    #[default]
+    Menu,
+    Playing,
+}
+
+#[auto_add_sub_state]
+#[derive(SubStates, Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[source(GameState = GameState::Playing)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Paused,
+}
+
+#[auto_add_sub_state(MenuState<bool>)]
+#[derive(SubStates, Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[source(GameState = GameState::Menu)]
+pub enum MenuState<T: Send + Sync + Clone + Eq + std::hash::Hash + std::fmt::Debug + 'static> {
+    #[default]
+    Main,
+    Options(T),
+}
+
+#[auto_plugin(app=app)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(StatesPlugin);
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_add_sub_state() {
+    let mut app = app();
+    app.update();
+    assert!(app.world().get_resource::<State<PauseState>>().is_none());
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+    app.update();
+    assert_eq!(
+        app.world().resource::<State<PauseState>>().get(),
+        &PauseState::Running
+    );
+    app.world_mut()
+        .resource_mut::<NextState<PauseState>>()
+        .set(PauseState::Paused);
+    app.update();
+    assert_eq!(
+        app.world().resource::<State<PauseState>>().get(),
+        &PauseState::Paused
+    );
+}
+
+#[test]
+fn test_auto_add_sub_state_generic() {
+    let mut app = app();
+    app.update();
+    assert_eq!(
+        app.world().resource::<State<MenuState<bool>>>().get(),
+        &MenuState::Main
+    );
+    app.world_mut()
+        .resource_mut::<NextState<MenuState<bool>>>()
+        .set(MenuState::Options(true));
+    app.update();
+    assert_eq!(
+        app.world().resource::<State<MenuState<bool>>>().get(),
+        &MenuState::Options(true)
+    );
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+    app.update();
+    assert!(app
+        .world()
+        .get_resource::<State<MenuState<bool>>>()
+        .is_none());
+}
@@ -0,0 +1,40 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_ecs::prelude::*;
+
+#[auto_init_resource]
+#[derive(Resource, Default)]
+pub struct Counter(pub usize);
+
+#[derive(Resource)]
+pub struct Enabled;
+
+#[derive(Resource)]
+pub struct Paused;
+
+#[auto_add_system(schedule = Update, run_if = resource_exists::<Enabled>, run_if = not(resource_exists::<Paused>))]
+fn increment(mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+}
+
+#[auto_plugin(app=app)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_add_system_run_if() {
+    let mut app = app();
+    app.update();
+    assert_eq!(app.world().resource::<Counter>().0, 0);
+    app.insert_resource(Enabled);
+    app.update();
+    assert_eq!(app.world().resource::<Counter>().0, 1);
+    app.insert_resource(Paused);
+    app.update();
+    assert_eq!(app.world().resource::<Counter>().0, 1);
+}
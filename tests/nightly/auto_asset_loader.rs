@@ -0,0 +1,123 @@
+use bevy_app::prelude::*;
+use bevy_asset::io::Reader;
+use bevy_asset::{Asset, AssetLoader, AssetPlugin, AssetServer, Assets, LoadContext};
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_reflect::prelude::*;
+
+#[auto_init_asset]
+#[derive(Asset, TypePath)]
+pub struct TextAsset {
+    pub loader: &'static str,
+    pub text: String,
+}
+
+// declared first, so the init loader below is registered after it and takes over `txt`
+#[auto_register_asset_loader(value = RegisteredTextLoader { loader: "register" })]
+pub struct RegisteredTextLoader {
+    pub loader: &'static str,
+}
+
+impl AssetLoader for RegisteredTextLoader {
+    type Asset = TextAsset;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<TextAsset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let text = String::from_utf8_lossy(&bytes).into_owned();
+        Ok(TextAsset {
+            loader: self.loader,
+            text,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["txt", "reg"]
+    }
+}
+
+#[auto_init_asset_loader]
+#[derive(Default)]
+pub struct InitTextLoader;
+
+impl AssetLoader for InitTextLoader {
+    type Asset = TextAsset;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<TextAsset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let text = String::from_utf8_lossy(&bytes).into_owned();
+        Ok(TextAsset {
+            loader: "init",
+            text,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["txt"]
+    }
+}
+
+#[auto_plugin(app=app)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(AssetPlugin {
+        file_path: "tests/assets".into(),
+        ..Default::default()
+    });
+    app.add_plugins(plugin);
+    app
+}
+
+fn load_text(app: &mut App, path: &'static str) -> TextAsset {
+    let handle = app
+        .world()
+        .resource::<AssetServer>()
+        .load::<TextAsset>(path);
+    for _ in 0..1000 {
+        app.update();
+        if let Some(asset) = app
+            .world_mut()
+            .resource_mut::<Assets<TextAsset>>()
+            .remove(&handle)
+        {
+            return asset;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    panic!("{path} did not load");
+}
+
+#[test]
+fn test_auto_register_asset_loader() {
+    let mut app = app();
+    let asset = load_text(&mut app, "greeting.reg");
+    assert_eq!(asset.loader, "register");
+    assert_eq!(asset.text, "hello\n");
+}
+
+#[test]
+fn test_auto_init_asset_loader() {
+    let mut app = app();
+    let asset = load_text(&mut app, "greeting.txt");
+    assert_eq!(
+        asset.loader, "init",
+        "asset loaders were not added in declaration order"
+    );
+    assert_eq!(asset.text, "hello\n");
+}
@@ -0,0 +1,41 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_ecs::component::ComponentId;
+use bevy_ecs::prelude::*;
+use bevy_ecs::world::DeferredWorld;
+
+#[derive(Resource, Default, Debug, PartialEq)]
+pub struct HookCalls(pub Vec<&'static str>);
+
+#[auto_component_hooks(on_add = foo_on_add, on_remove = foo_on_remove)]
+#[derive(Component)]
+pub struct Foo;
+
+fn foo_on_add(mut world: DeferredWorld, _entity: Entity, _component_id: ComponentId) {
+    world.resource_mut::<HookCalls>().0.push("on_add");
+}
+
+fn foo_on_remove(mut world: DeferredWorld, _entity: Entity, _component_id: ComponentId) {
+    world.resource_mut::<HookCalls>().0.push("on_remove");
+}
+
+#[auto_plugin(app=app)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.init_resource::<HookCalls>();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_component_hooks() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(Foo).id();
+    app.world_mut().entity_mut(entity).remove::<Foo>();
+    assert_eq!(
+        app.world().resource::<HookCalls>(),
+        &HookCalls(vec!["on_add", "on_remove"])
+    );
+}
@@ -0,0 +1,84 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_ecs::prelude::*;
+
+#[auto_init_resource]
+#[derive(Resource, Default)]
+pub struct Order(pub Vec<&'static str>);
+
+#[derive(Resource)]
+pub struct Enabled;
+
+#[derive(Resource)]
+pub struct ThirdEnabled;
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InputSet;
+
+#[auto_configure_set(schedule = Update, chain, run_if = resource_exists::<Enabled>)]
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GameSet {
+    #[configure_set(after = InputSet)]
+    First,
+    Second,
+    #[configure_set(run_if = resource_exists::<ThirdEnabled>)]
+    Third,
+}
+
+#[auto_add_system(schedule = Update, in_set = GameSet::Third)]
+fn third(mut order: ResMut<Order>) {
+    order.0.push("third");
+}
+
+#[auto_add_system(schedule = Update, in_set = GameSet::Second)]
+fn second(mut order: ResMut<Order>) {
+    order.0.push("second");
+}
+
+#[auto_add_system(schedule = Update, in_set = GameSet::First)]
+fn first(mut order: ResMut<Order>) {
+    order.0.push("first");
+}
+
+#[auto_add_system(schedule = Update, in_set = InputSet)]
+fn input(mut order: ResMut<Order>) {
+    order.0.push("input");
+}
+
+#[auto_plugin(app=app)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_configure_set() {
+    let mut app = app();
+    app.update();
+    assert_eq!(
+        app.world().resource::<Order>().0,
+        vec!["input"],
+        "set run condition not applied"
+    );
+
+    app.world_mut().resource_mut::<Order>().0.clear();
+    app.world_mut().insert_resource(Enabled);
+    app.update();
+    assert_eq!(
+        app.world().resource::<Order>().0,
+        vec!["input", "first", "second"],
+        "sets not configured in order"
+    );
+
+    app.world_mut().resource_mut::<Order>().0.clear();
+    app.world_mut().insert_resource(ThirdEnabled);
+    app.update();
+    assert_eq!(
+        app.world().resource::<Order>().0,
+        vec!["input", "first", "second", "third"],
+        "variant run condition not applied"
+    );
+}
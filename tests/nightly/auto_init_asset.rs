@@ -0,0 +1,62 @@
+use bevy_app::prelude::*;
+use bevy_asset::{Asset, AssetPlugin, Assets, Handle, ReflectAsset};
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+
+#[auto_init_asset]
+#[derive(Asset, TypePath)]
+pub struct FooAsset;
+
+#[auto_init_asset]
+#[derive(Asset, Reflect)]
+pub struct BarAsset {
+    pub value: usize,
+}
+
+#[auto_plugin(app=app)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(AssetPlugin::default());
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_init_asset() {
+    let app = app();
+    assert!(
+        app.world().contains_resource::<Assets<FooAsset>>(),
+        "did not auto init asset"
+    );
+    assert!(
+        app.world().contains_resource::<Assets<BarAsset>>(),
+        "did not auto init asset"
+    );
+}
+
+#[test]
+fn test_auto_init_asset_reflect() {
+    let app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().read();
+    assert!(
+        type_registry
+            .get_type_data::<ReflectAsset>(std::any::TypeId::of::<BarAsset>())
+            .is_some(),
+        "did not auto register asset reflect"
+    );
+    assert!(
+        type_registry
+            .get(std::any::TypeId::of::<Handle<BarAsset>>())
+            .is_some(),
+        "did not auto register asset handle type"
+    );
+    assert!(
+        type_registry
+            .get_type_data::<ReflectAsset>(std::any::TypeId::of::<FooAsset>())
+            .is_none(),
+        "registered asset reflect for a non reflect asset"
+    );
+}
@@ -0,0 +1,28 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_ecs::prelude::*;
+
+#[auto_insert_resource(value = FooResource::new(10))]
+#[derive(Resource, Debug, PartialEq)]
+pub struct FooResource(pub usize);
+
+impl FooResource {
+    pub fn new(value: usize) -> Self {
+        Self(value)
+    }
+}
+
+#[auto_plugin(app=app)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_insert_resource() {
+    let app = app();
+    assert_eq!(app.world().resource::<FooResource>(), &FooResource(10));
+}
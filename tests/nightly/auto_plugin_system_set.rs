@@ -0,0 +1,62 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_ecs::prelude::*;
+
+#[auto_init_resource]
+#[derive(Resource, Default)]
+pub struct Order(pub Vec<&'static str>);
+
+#[auto_add_system(schedule = Update)]
+fn a(mut order: ResMut<Order>) {
+    order.0.push("a");
+}
+
+#[auto_add_system(schedule = Update, chain = b_chain)]
+fn b(mut order: ResMut<Order>) {
+    order.0.push("b");
+}
+
+#[auto_add_system(schedule = Update, chain = b_chain)]
+fn c(mut order: ResMut<Order>) {
+    order.0.push("c");
+}
+
+#[derive(Resource)]
+struct Disabled;
+
+fn after_plugin(mut order: ResMut<Order>) {
+    order.0.push("after_plugin");
+}
+
+#[auto_plugin(app=app, system_set)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app.add_systems(Update, after_plugin.after(PluginSet));
+    app.configure_sets(Update, PluginSet.run_if(not(resource_exists::<Disabled>)));
+    app
+}
+
+#[test]
+fn test_auto_plugin_system_set() {
+    let mut app = app();
+    app.update();
+    let order = &app.world().resource::<Order>().0;
+    assert_eq!(order.len(), 4);
+    assert_eq!(
+        order.last(),
+        Some(&"after_plugin"),
+        "plugin systems not in PluginSet"
+    );
+
+    app.world_mut().resource_mut::<Order>().0.clear();
+    app.world_mut().insert_resource(Disabled);
+    app.update();
+    assert_eq!(
+        app.world().resource::<Order>().0,
+        vec!["after_plugin"],
+        "PluginSet run condition not applied to plugin systems"
+    );
+}
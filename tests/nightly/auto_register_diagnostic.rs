@@ -0,0 +1,33 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_diagnostic::{DiagnosticPath, DiagnosticsStore};
+
+#[auto_register_diagnostic]
+pub const FOO: DiagnosticPath = DiagnosticPath::const_new("foo");
+
+#[auto_register_diagnostic(max_history = 20, suffix = "ms")]
+pub const BAR: DiagnosticPath = DiagnosticPath::const_new("bar");
+
+#[auto_plugin(app=app)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_register_diagnostic() {
+    let app = app();
+    let diagnostics = app.world().resource::<DiagnosticsStore>();
+    assert!(
+        diagnostics.get(&FOO).is_some(),
+        "did not auto register diagnostic"
+    );
+    let bar = diagnostics
+        .get(&BAR)
+        .expect("did not auto register diagnostic");
+    assert_eq!(bar.get_max_history_length(), 20);
+    assert_eq!(bar.suffix, "ms");
+}
@@ -0,0 +1,42 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_ecs::prelude::*;
+
+#[auto_init_resource]
+#[derive(Resource, Default)]
+pub struct Counter(pub usize);
+
+#[auto_register_one_shot]
+fn spawn_wave(mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+}
+
+#[auto_register_one_shot]
+fn double(In(value): In<usize>) -> usize {
+    value * 2
+}
+
+#[auto_plugin(app=app)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_register_one_shot() {
+    let mut app = app();
+    let one_shot_systems = *app.world().resource::<OneShotSystems>();
+    app.world_mut()
+        .run_system(one_shot_systems.spawn_wave)
+        .expect("failed to run one-shot system");
+    assert_eq!(app.world().resource::<Counter>().0, 1);
+    assert_eq!(
+        app.world_mut()
+            .run_system_with_input(one_shot_systems.double, 2)
+            .expect("failed to run one-shot system"),
+        4
+    );
+}
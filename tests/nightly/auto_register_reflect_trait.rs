@@ -0,0 +1,45 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use std::any::TypeId;
+
+#[reflect_trait]
+pub trait Ability {
+    fn name(&self) -> &'static str;
+}
+
+#[auto_register_type]
+#[derive(Reflect)]
+pub struct Fireball;
+
+#[auto_register_reflect_trait]
+impl Ability for Fireball {
+    fn name(&self) -> &'static str {
+        "fireball"
+    }
+}
+
+#[auto_plugin(app=app)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_register_reflect_trait() {
+    let app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    let reflect_ability = type_registry
+        .get_type_data::<ReflectAbility>(TypeId::of::<Fireball>())
+        .expect("did not auto register reflect trait");
+    let fireball: Box<dyn Reflect> = Box::new(Fireball);
+    let ability = reflect_ability
+        .get(fireball.as_ref())
+        .expect("expected Fireball to implement Ability");
+    assert_eq!(ability.name(), "fireball");
+}
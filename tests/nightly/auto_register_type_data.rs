@@ -0,0 +1,43 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use std::any::TypeId;
+
+#[auto_register_type]
+#[auto_register_type_data(ReflectDefault)]
+#[derive(Reflect, Default)]
+pub struct Foo;
+
+#[auto_register_type(FooWithGeneric<u32>)]
+#[auto_register_type_data(FooWithGeneric<u32>, ReflectDefault)]
+#[derive(Reflect, Default)]
+pub struct FooWithGeneric<T>(pub T);
+
+#[auto_plugin(app=app)]
+fn plugin(app: &mut App) {}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_register_type_data() {
+    let app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    assert!(
+        type_registry
+            .get_type_data::<ReflectDefault>(TypeId::of::<Foo>())
+            .is_some(),
+        "did not auto register type data"
+    );
+    assert!(
+        type_registry
+            .get_type_data::<ReflectDefault>(TypeId::of::<FooWithGeneric<u32>>())
+            .is_some(),
+        "did not auto register type data for generic instance"
+    );
+}
@@ -1,20 +1,70 @@
 #[cfg(feature = "nightly_proc_macro_span")]
+mod auto_add_computed_state;
+#[cfg(feature = "nightly_proc_macro_span")]
 mod auto_add_event;
 #[cfg(feature = "nightly_proc_macro_span")]
 mod auto_add_event_generic;
 #[cfg(feature = "nightly_proc_macro_span")]
+mod auto_add_observer;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_add_schedule;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_add_sub_state;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_add_system;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_add_system_ordering;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_add_system_run_if;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_asset_collection;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_asset_loader;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_component_hooks;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_configure_set;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_init_asset;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_init_gizmo_group;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_init_non_send_resource;
+#[cfg(feature = "nightly_proc_macro_span")]
 mod auto_init_resource;
 #[cfg(feature = "nightly_proc_macro_span")]
+mod auto_init_resource_from_file;
+#[cfg(feature = "nightly_proc_macro_span")]
 mod auto_init_resource_generic;
 #[cfg(feature = "nightly_proc_macro_span")]
+mod auto_init_state;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_insert_resource;
+#[cfg(feature = "nightly_proc_macro_span")]
 mod auto_name;
 #[cfg(feature = "nightly_proc_macro_span")]
 mod auto_name_with_generics;
 #[cfg(feature = "nightly_proc_macro_span")]
+mod auto_on_state;
+#[cfg(feature = "nightly_proc_macro_span")]
 mod auto_plugin_param;
 #[cfg(feature = "nightly_proc_macro_span")]
+mod auto_plugin_system_set;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_register_diagnostic;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_register_one_shot;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_register_reflect_trait;
+#[cfg(feature = "nightly_proc_macro_span")]
 mod auto_register_type;
 #[cfg(feature = "nightly_proc_macro_span")]
+mod auto_register_type_data;
+#[cfg(feature = "nightly_proc_macro_span")]
 mod auto_register_type_generic;
 #[cfg(feature = "nightly_proc_macro_span")]
+mod auto_require;
+#[cfg(feature = "nightly_proc_macro_span")]
+mod auto_state_scoped;
+#[cfg(feature = "nightly_proc_macro_span")]
 mod ui_tests;
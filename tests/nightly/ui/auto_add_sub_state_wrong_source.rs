@@ -0,0 +1,26 @@
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_state::prelude::*;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub enum NotAState {
+    #[default]
+    Menu,
+    Playing,
+}
+
+#[auto_add_sub_state]
+#[derive(SubStates, Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[source(NotAState = NotAState::Playing)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Paused,
+}
+
+#[auto_plugin(app=_app)]
+fn plugin(_app: &mut bevy_app::App) {}
+
+// dummy main
+fn main() {
+    
+}
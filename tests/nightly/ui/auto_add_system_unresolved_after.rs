@@ -0,0 +1,13 @@
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_ecs::prelude::*;
+
+#[auto_add_system(schedule = bevy_app::Update, after = missing_system)]
+fn system() {}
+
+#[auto_plugin(app=_app)]
+fn plugin(_app: &mut bevy_app::App) {}
+
+// dummy main
+fn main() {
+    
+}
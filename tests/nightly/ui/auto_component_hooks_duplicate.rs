@@ -0,0 +1,16 @@
+use bevy_auto_plugin::auto_plugin::*;
+use bevy_ecs::component::ComponentId;
+use bevy_ecs::prelude::*;
+use bevy_ecs::world::DeferredWorld;
+
+#[auto_component_hooks(on_add = foo_on_add)]
+#[auto_component_hooks(on_add = foo_on_add)]
+#[derive(Component)]
+struct Foo;
+
+fn foo_on_add(_world: DeferredWorld, _entity: Entity, _component_id: ComponentId) {}
+
+#[auto_plugin(app=_app)]
+fn plugin(_app: &mut bevy_app::prelude::App) {}
+
+fn main() {}
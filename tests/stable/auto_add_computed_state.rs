@@ -0,0 +1,83 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin_module::*;
+use bevy_state::app::StatesPlugin;
+use bevy_state::prelude::*;
+use std::marker::PhantomData;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[auto_init_state]
+    #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+    pub enum GameState {
+        #[default]
+        Menu,
+        Playing,
+    }
+
+    #[auto_add_computed_state]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct InGame;
+
+    impl ComputedStates for InGame {
+        type SourceStates = GameState;
+
+        fn compute(sources: GameState) -> Option<Self> {
+            (sources == GameState::Playing).then_some(InGame)
+        }
+    }
+
+    #[auto_add_computed_state(InMenu<bool>)]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct InMenu<T>(pub PhantomData<T>);
+
+    impl<T: Send + Sync + Clone + Eq + std::hash::Hash + std::fmt::Debug + 'static> ComputedStates
+        for InMenu<T>
+    {
+        type SourceStates = GameState;
+
+        fn compute(sources: GameState) -> Option<Self> {
+            (sources == GameState::Menu).then_some(InMenu(PhantomData))
+        }
+    }
+}
+use plugin_module::*;
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(StatesPlugin);
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_add_computed_state() {
+    let mut app = app();
+    app.update();
+    assert!(app.world().get_resource::<State<InGame>>().is_none());
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+    app.update();
+    assert_eq!(app.world().resource::<State<InGame>>().get(), &InGame);
+}
+
+#[test]
+fn test_auto_add_computed_state_generic() {
+    let mut app = app();
+    app.update();
+    assert_eq!(
+        app.world().resource::<State<InMenu<bool>>>().get(),
+        &InMenu(PhantomData)
+    );
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+    app.update();
+    assert!(app.world().get_resource::<State<InMenu<bool>>>().is_none());
+}
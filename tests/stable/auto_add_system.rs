@@ -0,0 +1,39 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin_module::*;
+use bevy_ecs::prelude::*;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[auto_init_resource]
+    #[derive(Resource, Default)]
+    pub struct Counter(pub usize);
+
+    #[auto_add_system(schedule = Update)]
+    fn increment(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+}
+use plugin_module::*;
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_add_system() {
+    let mut app = app();
+    app.update();
+    assert_eq!(
+        app.world().resource::<Counter>().0,
+        1,
+        "did not auto add system"
+    );
+}
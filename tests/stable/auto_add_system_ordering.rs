@@ -0,0 +1,53 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin_module::*;
+use bevy_ecs::prelude::*;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[auto_init_resource]
+    #[derive(Resource, Default)]
+    pub struct Order(pub Vec<&'static str>);
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct FirstSet;
+
+    #[auto_add_system(schedule = Update, after = b, before = c)]
+    fn a(mut order: ResMut<Order>) {
+        order.0.push("a");
+    }
+
+    #[auto_add_system(schedule = Update, in_set = FirstSet)]
+    fn b(mut order: ResMut<Order>) {
+        order.0.push("b");
+    }
+
+    #[auto_add_system(schedule = Update, after = FirstSet, chain = c_chain)]
+    fn c(mut order: ResMut<Order>) {
+        order.0.push("c");
+    }
+
+    #[auto_add_system(schedule = Update, chain = c_chain)]
+    fn d(mut order: ResMut<Order>) {
+        order.0.push("d");
+    }
+}
+use plugin_module::*;
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_add_system_ordering() {
+    let mut app = app();
+    app.update();
+    assert_eq!(app.world().resource::<Order>().0, vec!["b", "a", "c", "d"]);
+}
@@ -0,0 +1,129 @@
+use bevy_app::prelude::*;
+use bevy_asset::io::Reader;
+use bevy_asset::{
+    Asset, AssetLoader, AssetPlugin, AssetServer, Assets, Handle, LoadContext, LoadState,
+};
+use bevy_auto_plugin::asset_collection::{asset_collection_loaded, AssetCollection};
+use bevy_auto_plugin::auto_plugin_module::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::RunSystemOnce;
+use bevy_reflect::prelude::*;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[auto_init_asset]
+    #[derive(Asset, TypePath)]
+    pub struct TextAsset(pub String);
+
+    #[auto_init_asset_loader]
+    #[derive(Default)]
+    pub struct TextLoader;
+
+    impl AssetLoader for TextLoader {
+        type Asset = TextAsset;
+        type Settings = ();
+        type Error = std::io::Error;
+
+        async fn load(
+            &self,
+            reader: &mut dyn Reader,
+            _settings: &(),
+            _load_context: &mut LoadContext<'_>,
+        ) -> Result<TextAsset, Self::Error> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(TextAsset(String::from_utf8_lossy(&bytes).into_owned()))
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+    }
+
+    #[auto_asset_collection]
+    #[derive(Resource)]
+    pub struct TextAssets {
+        #[asset(path = "greeting.txt")]
+        pub greeting: Handle<TextAsset>,
+        pub fallback: Handle<TextAsset>,
+    }
+
+    #[auto_init_resource]
+    #[derive(Resource, Default)]
+    pub struct Greeting(pub Option<String>);
+
+    #[auto_add_system(schedule = Update, run_if = asset_collection_loaded::<TextAssets>())]
+    fn greet(
+        assets: Res<TextAssets>,
+        texts: Res<Assets<TextAsset>>,
+        mut greeting: ResMut<Greeting>,
+    ) {
+        greeting.0 = texts.get(&assets.greeting).map(|text| text.0.clone());
+    }
+}
+use plugin_module::*;
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(AssetPlugin {
+        file_path: "tests/assets".into(),
+        ..Default::default()
+    });
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_asset_collection_load_system() {
+    let mut app = app();
+    assert!(
+        app.world().get_resource::<TextAssets>().is_none(),
+        "asset collection inserted before startup"
+    );
+    app.update();
+    let assets = app
+        .world()
+        .get_resource::<TextAssets>()
+        .expect("did not insert asset collection");
+    assert_eq!(
+        assets.greeting.path().map(ToString::to_string).as_deref(),
+        Some("greeting.txt")
+    );
+    assert_eq!(assets.fallback, Handle::default());
+    let load_state = app
+        .world()
+        .resource::<AssetServer>()
+        .load_state(assets.greeting.id());
+    assert!(
+        !matches!(load_state, LoadState::NotLoaded),
+        "did not start loading the asset collection"
+    );
+}
+
+#[test]
+fn test_auto_asset_collection_loaded_condition() {
+    let mut app = app();
+    for _ in 0..1000 {
+        app.update();
+        let loaded = app
+            .world_mut()
+            .run_system_once(TextAssets::loaded_condition())
+            .expect("failed to run loaded condition");
+        if loaded {
+            app.update();
+            let greeting = app.world().resource::<Greeting>();
+            assert_eq!(greeting.0.as_deref(), Some("hello\n"));
+            return;
+        }
+        let greeting = app.world().resource::<Greeting>();
+        assert_eq!(greeting.0, None, "system ran before the collection loaded");
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    panic!("asset collection did not load");
+}
@@ -0,0 +1,64 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin_module::*;
+use bevy_gizmos::config::{GizmoConfig, GizmoConfigGroup, GizmoConfigStore};
+use bevy_reflect::prelude::*;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[auto_init_gizmo_group]
+    #[derive(GizmoConfigGroup, Default, Reflect)]
+    pub struct DebugGizmos;
+
+    #[auto_init_gizmo_group(config = GizmoConfig { line_width: 4.0, ..Default::default() })]
+    #[derive(GizmoConfigGroup, Default, Reflect)]
+    pub struct WideGizmos;
+
+    #[auto_init_gizmo_group(config = GizmoConfig { line_width: 2.0, ..Default::default() }, generics(bool))]
+    #[derive(GizmoConfigGroup, Default, Reflect)]
+    pub struct GenericGizmos<T: TypePath + Default + Send + Sync>(pub T);
+}
+use plugin_module::*;
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_init_gizmo_group() {
+    let app = app();
+    let store = app.world().resource::<GizmoConfigStore>();
+    assert!(
+        store
+            .get_config_dyn(&std::any::TypeId::of::<DebugGizmos>())
+            .is_some(),
+        "did not auto init gizmo group"
+    );
+}
+
+#[test]
+fn test_auto_insert_gizmo_config() {
+    let app = app();
+    let store = app.world().resource::<GizmoConfigStore>();
+    let (config, _) = store
+        .get_config_dyn(&std::any::TypeId::of::<WideGizmos>())
+        .expect("did not auto insert gizmo config");
+    assert_eq!(config.line_width, 4.0);
+}
+
+#[test]
+fn test_auto_insert_gizmo_config_generic() {
+    let app = app();
+    let store = app.world().resource::<GizmoConfigStore>();
+    let (config, _) = store
+        .get_config_dyn(&std::any::TypeId::of::<GenericGizmos<bool>>())
+        .expect("did not auto insert generic gizmo config");
+    assert_eq!(config.line_width, 2.0);
+}
@@ -0,0 +1,40 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin_module::*;
+use std::marker::PhantomData;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[auto_init_non_send_resource]
+    #[derive(Default)]
+    pub struct FooNonSendResource(pub PhantomData<*const ()>);
+
+    #[auto_init_non_send_resource(FooNonSendResourceWithGeneric<bool>)]
+    #[derive(Default)]
+    pub struct FooNonSendResourceWithGeneric<T>(pub PhantomData<*const T>);
+}
+use plugin_module::*;
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_init_non_send_resource() {
+    let app = app();
+    assert!(app
+        .world()
+        .get_non_send_resource::<FooNonSendResource>()
+        .is_some());
+    assert!(app
+        .world()
+        .get_non_send_resource::<FooNonSendResourceWithGeneric<bool>>()
+        .is_some());
+}
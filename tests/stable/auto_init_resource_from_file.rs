@@ -0,0 +1,65 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin_module::*;
+use bevy_ecs::prelude::*;
+use serde::Deserialize;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[auto_init_resource(from_file = "tests/assets/physics.toml")]
+    #[derive(Resource, Deserialize, Debug, PartialEq)]
+    pub struct PhysicsConfig {
+        pub gravity: f32,
+        pub substeps: usize,
+    }
+
+    #[auto_init_resource(from_file = "tests/assets/audio.ron")]
+    #[derive(Resource, Deserialize, Debug, PartialEq)]
+    pub struct AudioConfig {
+        pub volume: f32,
+        pub muted: bool,
+        pub tracks: Vec<String>,
+        pub fade: Option<u8>,
+        #[serde(default)]
+        pub channels: u8,
+    }
+}
+use plugin_module::*;
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_init_resource_from_file() {
+    let app = app();
+    assert_eq!(
+        app.world().resource::<PhysicsConfig>(),
+        &PhysicsConfig {
+            gravity: -9.81,
+            substeps: 4,
+        }
+    );
+}
+
+#[test]
+fn test_auto_init_resource_from_ron_file() {
+    let app = app();
+    assert_eq!(
+        app.world().resource::<AudioConfig>(),
+        &AudioConfig {
+            volume: 0.5,
+            muted: false,
+            tracks: vec!["menu".to_string(), "battle".to_string()],
+            fade: Some(2),
+            channels: 0,
+        }
+    );
+}
@@ -0,0 +1,65 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin_module::*;
+use bevy_state::app::StatesPlugin;
+use bevy_state::prelude::*;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[auto_init_state]
+    #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+    pub enum GameState {
+        #[default]
+        Menu,
+        Playing,
+    }
+
+    #[auto_init_state(value = MenuState::Settings)]
+    #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+    pub enum MenuState {
+        #[default]
+        Main,
+        Settings,
+    }
+}
+use plugin_module::*;
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(StatesPlugin);
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_init_state() {
+    let mut app = app();
+    app.update();
+    assert_eq!(
+        app.world().resource::<State<GameState>>().get(),
+        &GameState::Menu
+    );
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+    app.update();
+    assert_eq!(
+        app.world().resource::<State<GameState>>().get(),
+        &GameState::Playing
+    );
+}
+
+#[test]
+fn test_auto_init_state_with_value() {
+    let mut app = app();
+    app.update();
+    assert_eq!(
+        app.world().resource::<State<MenuState>>().get(),
+        &MenuState::Settings
+    );
+}
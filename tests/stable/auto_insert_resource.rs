@@ -0,0 +1,35 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin_module::*;
+use bevy_ecs::prelude::*;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[auto_insert_resource(value = FooResource::new(10))]
+    #[derive(Resource, Debug, PartialEq)]
+    pub struct FooResource(pub usize);
+
+    impl FooResource {
+        pub fn new(value: usize) -> Self {
+            Self(value)
+        }
+    }
+}
+use plugin_module::*;
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_insert_resource() {
+    let app = app();
+    assert_eq!(app.world().resource::<FooResource>(), &FooResource(10));
+}
@@ -0,0 +1,74 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin_module::*;
+use bevy_ecs::prelude::*;
+use bevy_state::app::StatesPlugin;
+use bevy_state::prelude::*;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+    pub enum GameState {
+        #[default]
+        Menu,
+        Playing,
+    }
+
+    #[auto_init_resource]
+    #[derive(Resource, Default)]
+    pub struct Log(pub Vec<&'static str>);
+
+    #[auto_on_enter(state = GameState::Menu)]
+    fn enter_menu(mut log: ResMut<Log>) {
+        log.0.push("enter_menu");
+    }
+
+    #[auto_on_exit(state = GameState::Menu)]
+    fn exit_menu(mut log: ResMut<Log>) {
+        log.0.push("exit_menu");
+    }
+
+    #[auto_on_transition(exited = GameState::Menu, entered = GameState::Playing)]
+    fn menu_to_playing(mut log: ResMut<Log>) {
+        log.0.push("menu_to_playing");
+    }
+
+    #[auto_on_enter(state = GameState::Playing)]
+    fn enter_playing(mut log: ResMut<Log>) {
+        log.0.push("enter_playing");
+    }
+}
+use plugin_module::*;
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(StatesPlugin);
+    app.init_state::<GameState>();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_on_state() {
+    let mut app = app();
+    app.update();
+    assert_eq!(app.world().resource::<Log>().0, vec!["enter_menu"]);
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+    app.update();
+    assert_eq!(
+        app.world().resource::<Log>().0,
+        vec![
+            "enter_menu",
+            "exit_menu",
+            "menu_to_playing",
+            "enter_playing"
+        ]
+    );
+}
@@ -0,0 +1,37 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin_module::*;
+use bevy_ecs::prelude::*;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    pub struct Marker;
+
+    #[derive(Component, Debug, PartialEq)]
+    pub struct Health(pub usize);
+
+    #[auto_require(Marker, Health = Health(100))]
+    #[derive(Component)]
+    pub struct Player;
+}
+use plugin_module::*;
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_require() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(Player).id();
+    assert_eq!(app.world().get::<Marker>(entity), Some(&Marker));
+    assert_eq!(app.world().get::<Health>(entity), Some(&Health(100)));
+}
@@ -0,0 +1,55 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::auto_plugin_module::*;
+use bevy_ecs::prelude::*;
+use bevy_state::app::StatesPlugin;
+use bevy_state::prelude::*;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[auto_init_state(scoped_entities)]
+    #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+    pub enum GameState {
+        #[default]
+        Menu,
+        InGame,
+    }
+
+    #[auto_state_scoped(GameState::InGame)]
+    #[derive(Component)]
+    pub struct Level;
+}
+use plugin_module::*;
+
+fn plugin(app: &mut App) {
+    plugin_module::init(app);
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(StatesPlugin);
+    app.add_plugins(plugin);
+    app
+}
+
+#[test]
+fn test_auto_state_scoped() {
+    let mut app = app();
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::InGame);
+    app.update();
+    let entity = app.world_mut().spawn(Level).id();
+    assert_eq!(
+        app.world()
+            .get::<StateScoped<GameState>>(entity)
+            .map(|scoped| &scoped.0),
+        Some(&GameState::InGame)
+    );
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Menu);
+    app.update();
+    assert!(app.world().get_entity(entity).is_err());
+}
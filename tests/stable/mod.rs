@@ -1,9 +1,35 @@
+mod auto_add_computed_state;
 mod auto_add_event;
 mod auto_add_event_generic;
+mod auto_add_observer;
+mod auto_add_schedule;
+mod auto_add_sub_state;
+mod auto_add_system;
+mod auto_add_system_ordering;
+mod auto_add_system_run_if;
+mod auto_asset_collection;
+mod auto_asset_loader;
+mod auto_component_hooks;
+mod auto_configure_set;
+mod auto_init_asset;
+mod auto_init_gizmo_group;
+mod auto_init_non_send_resource;
 mod auto_init_resource;
+mod auto_init_resource_from_file;
 mod auto_init_resource_generic;
+mod auto_init_state;
+mod auto_insert_resource;
 mod auto_name;
 mod auto_name_with_generic;
+mod auto_on_state;
 mod auto_plugin_param;
+mod auto_plugin_system_set;
+mod auto_register_diagnostic;
+mod auto_register_one_shot;
+mod auto_register_reflect_trait;
 mod auto_register_type;
+mod auto_register_type_data;
 mod auto_register_type_generic;
+mod auto_require;
+mod auto_state_scoped;
+mod ui_tests;
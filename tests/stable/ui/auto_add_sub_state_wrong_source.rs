@@ -0,0 +1,28 @@
+use bevy_auto_plugin::auto_plugin_module::*;
+use bevy_state::prelude::*;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+    pub enum NotAState {
+        #[default]
+        Menu,
+        Playing,
+    }
+
+    #[auto_add_sub_state]
+    #[derive(SubStates, Debug, Default, Clone, PartialEq, Eq, Hash)]
+    #[source(NotAState = NotAState::Playing)]
+    pub enum PauseState {
+        #[default]
+        Running,
+        Paused,
+    }
+}
+
+// dummy main
+fn main() {
+    
+}
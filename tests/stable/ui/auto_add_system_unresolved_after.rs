@@ -0,0 +1,14 @@
+use bevy_auto_plugin::auto_plugin_module::*;
+
+#[auto_plugin(init_name=init)]
+mod plugin_module {
+    use super::*;
+
+    #[auto_add_system(schedule = bevy_app::Update, after = missing_system)]
+    fn system() {}
+}
+
+// dummy main
+fn main() {
+    
+}
@@ -0,0 +1,5 @@
+#[test]
+fn ui_tests() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/stable/ui/*.rs");
+}